@@ -66,20 +66,24 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Display answer box if available
     if let Some(answer_box) = results.answer_box {
         println!("💡 Answer Box:");
-        println!("   Type: {}", answer_box.answer_type);
+        println!("   Type: {}", answer_box.answer_type());
 
-        if let Some(title) = answer_box.title {
+        if let Some(title) = answer_box.title() {
             println!("   Title: {}", title);
         }
 
-        if let Some(answer) = answer_box.answer {
+        if let Some(answer) = answer_box.text() {
             println!("   Answer: {}", answer);
         }
 
-        if let Some(snippet) = answer_box.snippet {
+        if let Some(snippet) = answer_box.snippet() {
             println!("   Snippet: {}", snippet);
         }
 
+        if let Some(link) = answer_box.link() {
+            println!("   Source: {}", link);
+        }
+
         println!();
     }
 
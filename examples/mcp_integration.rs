@@ -88,10 +88,11 @@ impl SerpSearchTool {
         // Add answer box if available
         if let Some(answer_box) = results.answer_box {
             mcp_results["answer_box"] = json!({
-                "type": answer_box.answer_type,
-                "title": answer_box.title,
-                "answer": answer_box.answer,
-                "snippet": answer_box.snippet
+                "type": answer_box.answer_type(),
+                "title": answer_box.title(),
+                "answer": answer_box.text(),
+                "snippet": answer_box.snippet(),
+                "link": answer_box.link()
             });
         }
 
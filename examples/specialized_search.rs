@@ -217,7 +217,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("📍 Found {} local places:", places.len());
             for (i, place) in places.iter().enumerate() {
                 println!("{}. {}", i + 1, place.title);
-                println!("   📍 Address: {}", place.address);
+                if let Some(address) = &place.address {
+                    println!("   📍 Address: {}", address);
+                }
 
                 if let Some(rating) = place.rating {
                     println!("   ⭐ Rating: {:.1}", rating);
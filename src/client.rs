@@ -58,12 +58,14 @@
 //! ```
 
 use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT};
+use serde::de::DeserializeOwned;
 use std::time::Duration;
 use tracing::{debug, info, warn};
 
 use crate::{
+    engines::{self, EngineQuery},
     error::{SerpError, SerpResult},
-    query::{SearchQuery, SearchQueryBuilder},
+    query::SearchQueryBuilder,
     response::SearchResults,
     retry::RetryPolicy,
 };
@@ -596,14 +598,54 @@ impl SerpClient {
     ///
     /// # Errors
     ///
-    /// - [`SerpError::InvalidParameter`]: Query parameters are invalid
+    /// - [`SerpError::InvalidParameter`]: Query parameters are invalid, e.g.
+    ///   conflicting location, uule and ll targeting
     /// - [`SerpError::RateLimited`]: API rate limit exceeded
     /// - [`SerpError::ApiError`]: API returned an error response
     /// - [`SerpError::Network`]: Network communication failed
     /// - [`SerpError::InvalidResponse`]: Response parsing failed
     pub async fn search(&self, query: SearchQueryBuilder) -> SerpResult<SearchResults> {
-        let query = query.build(self.api_key.clone());
-        self.search_with_retry(query).await
+        let query = query.build(self.api_key.clone())?;
+        if let Ok(fingerprint) = query.fingerprint() {
+            debug!("Query fingerprint: {}", fingerprint);
+        }
+        self.search_with_retry(&query.to_query_string()?).await
+    }
+
+    /// Executes a query against one of SerpAPI's dedicated engines.
+    ///
+    /// Each engine query type (for example [`GoogleMapsQuery`]) knows its `engine`
+    /// parameter and the typed response it produces, so the result is returned
+    /// already deserialized into that engine's response structure. Requests go
+    /// through the same retry and rate limiting logic as [`search`](Self::search).
+    ///
+    /// [`GoogleMapsQuery`]: crate::engines::google_maps::GoogleMapsQuery
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serp_sdk::SerpClient;
+    /// use serp_sdk::engines::google_maps::GoogleMapsQuery;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SerpClient::new("test")?;
+    /// let results = client.search_engine(GoogleMapsQuery::search("coffee")).await?;
+    ///
+    /// for place in results.local_results.unwrap_or_default() {
+    ///     println!("{} ({:?})", place.title, place.rating);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Same as [`search`](Self::search), plus [`SerpError::InvalidParameter`] when
+    /// the engine query fails its own validation.
+    pub async fn search_engine<Q: EngineQuery>(&self, query: Q) -> SerpResult<Q::Response> {
+        let query_string = engines::to_query_string(&query, &self.api_key)?;
+        debug!("Executing {} engine search", query.engine());
+        self.search_with_retry(&query_string).await
     }
 
     /// Executes a search with automatic retry logic.
@@ -615,14 +657,14 @@ impl SerpClient {
     /// - Rate limiting (with respect to Retry-After header)
     ///
     /// The retry behavior is controlled by the configured [`RetryPolicy`].
-    async fn search_with_retry(&self, query: SearchQuery) -> SerpResult<SearchResults> {
+    async fn search_with_retry<T: DeserializeOwned>(&self, query_string: &str) -> SerpResult<T> {
         let mut retries = 0;
         let max_retries = self.retry_policy.max_retries;
 
         loop {
             debug!("Executing search request (attempt {})", retries + 1);
 
-            match self.execute_request(&query).await {
+            match self.execute_request(query_string).await {
                 Ok(results) => {
                     info!("Search completed successfully");
                     return Ok(results);
@@ -651,8 +693,7 @@ impl SerpClient {
     /// This method constructs the full request URL, sends the HTTP GET request,
     /// and parses the response. It handles various HTTP status codes and
     /// converts them to appropriate error types.
-    async fn execute_request(&self, query: &SearchQuery) -> SerpResult<SearchResults> {
-        let query_string = query.to_query_string()?;
+    async fn execute_request<T: DeserializeOwned>(&self, query_string: &str) -> SerpResult<T> {
         let url = format!("{}/search?{}", self.base_url, query_string);
 
         debug!("Making request to: {}", url.replace(&self.api_key, "***"));
@@ -691,7 +732,7 @@ impl SerpClient {
         // Parse successful response
         let response_text = response.text().await.map_err(SerpError::RequestFailed)?;
 
-        serde_json::from_str::<SearchResults>(&response_text)
+        serde_json::from_str::<T>(&response_text)
             .map_err(|e| SerpError::InvalidResponse(format!("JSON parse error: {}", e)))
     }
 
@@ -0,0 +1,262 @@
+#![allow(missing_docs)]
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+
+use crate::{
+    client::SerpClient,
+    engines::{has_next_page, EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    response::{ListingSummary, Price, ProductListing, SearchMetadata, SerpapiPagination},
+    streaming::StreamConfig,
+};
+
+/// Sort order for Amazon results (s parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum AmazonSort {
+    #[serde(rename = "relevanceblender")]
+    Featured,
+    #[serde(rename = "price-asc-rank")]
+    PriceLowToHigh,
+    #[serde(rename = "price-desc-rank")]
+    PriceHighToLow,
+    #[serde(rename = "review-rank")]
+    AverageReview,
+    #[serde(rename = "date-desc-rank")]
+    NewestArrivals,
+    #[serde(rename = "exact-aware-popularity-rank")]
+    BestSellers,
+}
+
+/// Query for the `amazon` engine.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use serp_sdk::{SerpClient, StreamConfig};
+/// use serp_sdk::engines::amazon::{AmazonQuery, AmazonSort};
+/// use serp_sdk::response::ProductListing;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = AmazonQuery::new("usb c hub")
+///     .domain("amazon.co.uk")
+///     .sort_by(AmazonSort::AverageReview);
+///
+/// let mut products = client.amazon_stream(query, StreamConfig::new().max_pages(2));
+/// while let Some(product) = products.next().await {
+///     let product = product?;
+///     println!("{} {:?}", product.title, product.summary().price);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct AmazonQuery {
+    k: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    amazon_domain: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delivery_zip: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    s: Option<AmazonSort>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl AmazonQuery {
+    /// Create an Amazon search (k parameter)
+    pub fn new(keywords: impl Into<String>) -> Self {
+        Self {
+            k: keywords.into(),
+            amazon_domain: None,
+            language: None,
+            delivery_zip: None,
+            s: None,
+            page: None,
+        }
+    }
+
+    /// Set the Amazon domain (amazon_domain parameter)
+    /// Examples: "amazon.com", "amazon.co.uk", "amazon.de"
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.amazon_domain = Some(domain.into());
+        self
+    }
+
+    /// Set the language (language parameter)
+    /// Examples: "en_US", "de_DE"
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Set the ZIP or postal code to deliver to (delivery_zip parameter)
+    pub fn delivery_zip(mut self, zip: impl Into<String>) -> Self {
+        self.delivery_zip = Some(zip.into());
+        self
+    }
+
+    /// Set the sort order (s parameter)
+    pub fn sort_by(mut self, sort: AmazonSort) -> Self {
+        self.s = Some(sort);
+        self
+    }
+
+    /// Set the one-based page number (page parameter)
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+impl EngineQuery for AmazonQuery {
+    type Response = AmazonResults;
+
+    fn engine(&self) -> &str {
+        "amazon"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        if self.page == Some(0) {
+            return Err(SerpError::InvalidParameter(
+                "Amazon pages start at 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn next_page(&self, response: &AmazonResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        has_next.then(|| {
+            let page = self.page.unwrap_or(1) + 1;
+            self.clone().page(page)
+        })
+    }
+}
+
+impl SerpClient {
+    /// Stream individual Amazon products across pages
+    pub fn amazon_stream(
+        &self,
+        query: AmazonQuery,
+        config: StreamConfig,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<AmazonProduct>> + Send + '_>> {
+        self.engine_items_stream(query, config, |page| {
+            page.organic_results.unwrap_or_default()
+        })
+    }
+}
+
+/// Results of an `amazon` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct AmazonResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub organic_results: Option<Vec<AmazonProduct>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Amazon product listing
+#[derive(Debug, Deserialize, Clone)]
+pub struct AmazonProduct {
+    pub position: Option<u32>,
+    pub asin: Option<String>,
+    pub title: String,
+    pub link: Option<String>,
+    pub link_clean: Option<String>,
+    pub thumbnail: Option<String>,
+    pub rating: Option<f32>,
+    pub reviews: Option<u64>,
+    pub price: Option<String>,
+    pub extracted_price: Option<f64>,
+    pub old_price: Option<String>,
+    pub extracted_old_price: Option<f64>,
+    pub prime: Option<bool>,
+    pub sponsored: Option<bool>,
+    /// e.g. "2K+ bought in past month"
+    pub bought_last_month: Option<String>,
+    pub delivery: Option<Vec<String>>,
+}
+
+impl ProductListing for AmazonProduct {
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn link(&self) -> Option<&str> {
+        self.link_clean.as_deref().or(self.link.as_deref())
+    }
+
+    fn summary(&self) -> ListingSummary {
+        ListingSummary {
+            price: Price::from_parts(self.price.as_deref(), self.extracted_price),
+            old_price: Price::from_parts(self.old_price.as_deref(), self.extracted_old_price),
+            rating: self.rating,
+            reviews: self.reviews,
+            seller: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_amazon_query_params() {
+        let query = AmazonQuery::new("usb c hub")
+            .domain("amazon.co.uk")
+            .sort_by(AmazonSort::PriceLowToHigh)
+            .page(2);
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("k".to_string(), "usb c hub".to_string()),
+                ("amazon_domain".to_string(), "amazon.co.uk".to_string()),
+                ("s".to_string(), "price-asc-rank".to_string()),
+                ("page".to_string(), "2".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_amazon_results_and_summary() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "organic_results": [{
+                "position": 1,
+                "asin": "B0BQ5M4V1T",
+                "title": "USB C Hub",
+                "link_clean": "https://www.amazon.co.uk/dp/B0BQ5M4V1T",
+                "rating": 4.5,
+                "reviews": 1532,
+                "price": "£24.99",
+                "extracted_price": 24.99,
+                "old_price": "£29.99",
+                "extracted_old_price": 29.99
+            }],
+            "serpapi_pagination": {"current": 1, "next": "https://serpapi.com/search.json?page=2"}
+        }"#;
+        let results: AmazonResults = serde_json::from_str(json).unwrap();
+        let summary = results.organic_results.as_ref().unwrap()[0].summary();
+        let price = summary.price.unwrap();
+        assert_eq!(price.amount, 24.99);
+        assert_eq!(price.currency.as_deref(), Some("GBP"));
+        assert_eq!(summary.old_price.unwrap().amount, 29.99);
+        assert_eq!(summary.reviews, Some(1532));
+
+        let next = AmazonQuery::new("usb c hub").next_page(&results).unwrap();
+        assert_eq!(next.page, Some(2));
+    }
+}
@@ -0,0 +1,416 @@
+#![allow(missing_docs)]
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::pin::Pin;
+
+use crate::{
+    client::SerpClient,
+    engines::{has_next_page, EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    response::{SearchMetadata, SerpapiPagination},
+    streaming::StreamConfig,
+};
+
+/// Device to search the App Store for (device parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AppleDevice {
+    Mobile,
+    Tablet,
+    Desktop,
+}
+
+/// Sort order for App Store reviews (sort parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AppleReviewSort {
+    MostRecent,
+    MostHelpful,
+    MostFavorable,
+    MostCritical,
+}
+
+/// Query for the `apple_app_store` engine.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::SerpClient;
+/// use serp_sdk::engines::apple_app_store::{AppleAppStoreQuery, AppleDevice};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = AppleAppStoreQuery::new("habit tracker")
+///     .country("us")
+///     .device(AppleDevice::Mobile)
+///     .limit(50)?;
+///
+/// let results = client.search_engine(query).await?;
+/// for app in results.organic_results.unwrap_or_default() {
+///     println!("{} {:?}", app.title, app.average_rating());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct AppleAppStoreQuery {
+    term: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lang: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    device: Option<AppleDevice>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    category_id: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl AppleAppStoreQuery {
+    /// Create an App Store search
+    pub fn new(term: impl Into<String>) -> Self {
+        Self {
+            term: term.into(),
+            country: None,
+            lang: None,
+            device: None,
+            category_id: None,
+            num: None,
+            page: None,
+        }
+    }
+
+    /// Set the storefront country (country parameter)
+    pub fn country(mut self, country: impl Into<String>) -> Self {
+        self.country = Some(country.into());
+        self
+    }
+
+    /// Set the language (lang parameter)
+    /// Examples: "en-us", "fr-fr", "ja-jp"
+    pub fn language(mut self, lang: impl Into<String>) -> Self {
+        self.lang = Some(lang.into());
+        self
+    }
+
+    /// Set the device to search apps for (device parameter)
+    pub fn device(mut self, device: AppleDevice) -> Self {
+        self.device = Some(device);
+        self
+    }
+
+    /// Restrict results to an App Store category ID (category_id parameter)
+    /// Examples: 6000 (Business), 6013 (Health & Fitness), 6014 (Games)
+    pub fn category(mut self, category_id: u32) -> Self {
+        self.category_id = Some(category_id);
+        self
+    }
+
+    /// Set the number of results per page (1-200)
+    pub fn limit(mut self, num: u32) -> SerpResult<Self> {
+        if num == 0 || num > 200 {
+            return Err(SerpError::InvalidParameter(
+                "limit must be between 1 and 200".to_string(),
+            ));
+        }
+        self.num = Some(num);
+        Ok(self)
+    }
+
+    /// Set the zero-based page number (page parameter)
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+impl EngineQuery for AppleAppStoreQuery {
+    type Response = AppleAppStoreResults;
+
+    fn engine(&self) -> &str {
+        "apple_app_store"
+    }
+
+    fn next_page(&self, response: &AppleAppStoreResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        has_next.then(|| {
+            let page = self.page.unwrap_or(0) + 1;
+            self.clone().page(page)
+        })
+    }
+}
+
+/// Query for the `apple_reviews` engine.
+///
+/// Use [`SerpClient::apple_reviews_stream`] to iterate over every review.
+#[derive(Debug, Clone, Serialize)]
+pub struct AppleReviewsQuery {
+    product_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<AppleReviewSort>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl AppleReviewsQuery {
+    /// Create a reviews query for an App Store product ID (e.g. "1507782672")
+    pub fn new(product_id: impl Into<String>) -> Self {
+        Self {
+            product_id: product_id.into(),
+            country: None,
+            sort: None,
+            page: None,
+        }
+    }
+
+    /// Set the storefront country (country parameter)
+    pub fn country(mut self, country: impl Into<String>) -> Self {
+        self.country = Some(country.into());
+        self
+    }
+
+    /// Set the sort order
+    pub fn sort_by(mut self, sort: AppleReviewSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Set the one-based page number (page parameter)
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+impl EngineQuery for AppleReviewsQuery {
+    type Response = AppleReviewsResults;
+
+    fn engine(&self) -> &str {
+        "apple_reviews"
+    }
+
+    fn next_page(&self, response: &AppleReviewsResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        has_next.then(|| {
+            let page = self.page.unwrap_or(1) + 1;
+            self.clone().page(page)
+        })
+    }
+}
+
+impl SerpClient {
+    /// Stream individual App Store reviews across pages
+    ///
+    /// Pages are requested until SerpAPI stops returning a next page or
+    /// `config.max_pages` is reached.
+    pub fn apple_reviews_stream(
+        &self,
+        query: AppleReviewsQuery,
+        config: StreamConfig,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<AppleReview>> + Send + '_>> {
+        self.engine_items_stream(query, config, |page| page.reviews.unwrap_or_default())
+    }
+}
+
+/// Results of an `apple_app_store` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct AppleAppStoreResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub organic_results: Option<Vec<AppleApp>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// App listed on the App Store
+#[derive(Debug, Deserialize, Clone)]
+pub struct AppleApp {
+    pub position: Option<u32>,
+    /// Product ID for [`AppleReviewsQuery`]
+    pub id: Option<u64>,
+    pub title: String,
+    pub bundle_id: Option<String>,
+    pub version: Option<String>,
+    pub link: Option<String>,
+    pub serpapi_product_link: Option<String>,
+    pub serpapi_reviews_link: Option<String>,
+    pub description: Option<String>,
+    pub release_note: Option<String>,
+    pub release_date: Option<String>,
+    pub latest_version_release_date: Option<String>,
+    pub age_rating: Option<String>,
+    pub minimum_os_version: Option<String>,
+    pub size_in_bytes: Option<u64>,
+    pub price: Option<ApplePrice>,
+    pub rating: Option<Vec<AppleRating>>,
+    pub genres: Option<Vec<AppleGenre>>,
+    pub developer: Option<AppleDeveloper>,
+    pub supported_languages: Option<Vec<String>>,
+    /// Screenshots per device family, e.g. "general" or "ipad"
+    pub screenshots: Option<HashMap<String, Vec<AppleImage>>>,
+    pub logos: Option<Vec<AppleImage>>,
+}
+
+impl AppleApp {
+    /// Average rating across all versions
+    pub fn average_rating(&self) -> Option<f32> {
+        let ratings = self.rating.as_ref()?;
+        ratings
+            .iter()
+            .find(|r| r.rating_type.as_deref() == Some("All Times"))
+            .or_else(|| ratings.first())
+            .and_then(|r| r.rating)
+    }
+}
+
+/// Price of an app
+#[derive(Debug, Deserialize, Clone)]
+pub struct ApplePrice {
+    /// "Free" or "Paid"
+    #[serde(rename = "type")]
+    pub price_type: Option<String>,
+    pub amount: Option<f64>,
+    pub currency: Option<String>,
+}
+
+/// Rating for all versions or the current version
+#[derive(Debug, Deserialize, Clone)]
+pub struct AppleRating {
+    /// e.g. "All Times" or "Current Version"
+    #[serde(rename = "type")]
+    pub rating_type: Option<String>,
+    pub rating: Option<f32>,
+    pub count: Option<u64>,
+}
+
+/// App Store category
+#[derive(Debug, Deserialize, Clone)]
+pub struct AppleGenre {
+    pub name: String,
+    pub id: Option<u32>,
+    pub primary: Option<bool>,
+}
+
+/// App developer
+#[derive(Debug, Deserialize, Clone)]
+pub struct AppleDeveloper {
+    pub name: String,
+    pub id: Option<u64>,
+    pub link: Option<String>,
+}
+
+/// App icon or screenshot
+#[derive(Debug, Deserialize, Clone)]
+pub struct AppleImage {
+    pub size: Option<String>,
+    pub link: Option<String>,
+}
+
+/// Results of an `apple_reviews` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct AppleReviewsResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub reviews: Option<Vec<AppleReview>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// App Store review
+#[derive(Debug, Deserialize, Clone)]
+pub struct AppleReview {
+    pub position: Option<u32>,
+    pub id: Option<String>,
+    pub title: Option<String>,
+    pub text: Option<String>,
+    pub rating: Option<f32>,
+    pub review_date: Option<String>,
+    pub reviewed_version: Option<String>,
+    pub author: Option<AppleReviewAuthor>,
+}
+
+/// Author of an App Store review
+#[derive(Debug, Deserialize, Clone)]
+pub struct AppleReviewAuthor {
+    pub name: String,
+    pub author_id: Option<String>,
+    pub author_link: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_app_store_query_params() {
+        let query = AppleAppStoreQuery::new("habit tracker")
+            .country("us")
+            .device(AppleDevice::Tablet)
+            .category(6013)
+            .limit(50)
+            .unwrap();
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("term".to_string(), "habit tracker".to_string()),
+                ("country".to_string(), "us".to_string()),
+                ("device".to_string(), "tablet".to_string()),
+                ("category_id".to_string(), "6013".to_string()),
+                ("num".to_string(), "50".to_string()),
+            ]
+        );
+
+        let reviews = AppleReviewsQuery::new("1507782672").sort_by(AppleReviewSort::MostCritical);
+        assert!(reviews
+            .params()
+            .unwrap()
+            .contains(&("sort".to_string(), "mostcritical".to_string())));
+    }
+
+    #[test]
+    fn test_app_store_results_parsing() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "organic_results": [{
+                "position": 1,
+                "id": 1507782672,
+                "title": "Streaks",
+                "price": {"type": "Paid", "amount": 5.99, "currency": "USD"},
+                "rating": [
+                    {"type": "Current Version", "rating": 4.6, "count": 120},
+                    {"type": "All Times", "rating": 4.8, "count": 9876}
+                ],
+                "developer": {"name": "Crunchy Bagel", "id": 123}
+            }]
+        }"#;
+        let results: AppleAppStoreResults = serde_json::from_str(json).unwrap();
+        let app = &results.organic_results.as_ref().unwrap()[0];
+        assert_eq!(app.average_rating(), Some(4.8));
+        assert!(AppleAppStoreQuery::new("x").next_page(&results).is_none());
+
+        let json = r#"{
+            "search_metadata": {"id": "def"},
+            "reviews": [{"position": 1, "id": "10", "title": "Great", "text": "Love it", "rating": 5,
+                         "author": {"name": "jo", "author_id": "99"}}],
+            "serpapi_pagination": {"next": "https://serpapi.com/search.json?page=2"}
+        }"#;
+        let results: AppleReviewsResults = serde_json::from_str(json).unwrap();
+        let next = AppleReviewsQuery::new("1").next_page(&results).unwrap();
+        assert_eq!(next.page, Some(2));
+    }
+}
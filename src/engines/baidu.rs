@@ -0,0 +1,152 @@
+#![allow(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    engines::{has_next_page, EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    response::{OrganicResult, RelatedSearch, SearchMetadata, SerpapiPagination},
+};
+
+/// Script of Baidu results (ct parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BaiduLanguage {
+    All,
+    SimplifiedChinese,
+    TraditionalChinese,
+}
+
+/// Query for the `baidu` engine.
+///
+/// Pages are reached with a `pn` offset advanced by the page size (`rn`).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::SerpClient;
+/// use serp_sdk::engines::baidu::{BaiduLanguage, BaiduQuery};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = BaiduQuery::new("咖啡")
+///     .language(BaiduLanguage::SimplifiedChinese)
+///     .limit(20);
+///
+/// let results = client.search_engine(query).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct BaiduQuery {
+    /// Baidu's own `wd` parameter; SerpAPI takes it as `q`
+    q: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ct: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rn: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pn: Option<u32>,
+}
+
+impl BaiduQuery {
+    /// Create a Baidu search (Baidu's wd parameter)
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            q: query.into(),
+            ct: None,
+            rn: None,
+            pn: None,
+        }
+    }
+
+    /// Restrict results to a script (ct parameter)
+    pub fn language(mut self, language: BaiduLanguage) -> Self {
+        self.ct = Some(match language {
+            BaiduLanguage::All => 1,
+            BaiduLanguage::SimplifiedChinese => 2,
+            BaiduLanguage::TraditionalChinese => 3,
+        });
+        self
+    }
+
+    /// Set the number of results per page (rn parameter, 1-50)
+    pub fn limit(mut self, rn: u32) -> Self {
+        self.rn = Some(rn);
+        self
+    }
+
+    /// Set the offset for pagination (pn parameter)
+    pub fn offset(mut self, pn: u32) -> Self {
+        self.pn = Some(pn);
+        self
+    }
+}
+
+impl EngineQuery for BaiduQuery {
+    type Response = BaiduResults;
+
+    fn engine(&self) -> &str {
+        "baidu"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        if self.rn.is_some_and(|rn| rn == 0 || rn > 50) {
+            return Err(SerpError::InvalidParameter(
+                "limit must be between 1 and 50".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn next_page(&self, response: &BaiduResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        has_next.then(|| {
+            let pn = self.pn.unwrap_or(0) + self.rn.unwrap_or(10);
+            self.clone().offset(pn)
+        })
+    }
+}
+
+/// Results of a `baidu` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct BaiduResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub organic_results: Option<Vec<OrganicResult>>,
+    pub related_searches: Option<Vec<RelatedSearch>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_baidu_params_and_next_page() {
+        let query = BaiduQuery::new("coffee")
+            .language(BaiduLanguage::SimplifiedChinese)
+            .limit(20);
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("q".to_string(), "coffee".to_string()),
+                ("ct".to_string(), "2".to_string()),
+                ("rn".to_string(), "20".to_string()),
+            ]
+        );
+        assert!(BaiduQuery::new("coffee").limit(51).validate().is_err());
+
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "organic_results": [{"position": 1, "title": "咖啡", "link": "https://baike.baidu.com/item/咖啡"}],
+            "related_searches": [{"query": "咖啡豆", "link": "https://www.baidu.com/s?wd=咖啡豆"}],
+            "serpapi_pagination": {"next": "https://serpapi.com/search.json?pn=20"}
+        }"#;
+        let results: BaiduResults = serde_json::from_str(json).unwrap();
+        assert_eq!(query.next_page(&results).unwrap().pn, Some(20));
+    }
+}
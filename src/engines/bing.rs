@@ -0,0 +1,446 @@
+#![allow(missing_docs)]
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+
+use crate::{
+    client::SerpClient,
+    engines::{has_next_page, EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    response::{
+        ImageResult, KnowledgeGraph, NewsResult, OrganicResult, RelatedSearch, SearchMetadata,
+        SerpapiPagination, ShoppingResult,
+    },
+    streaming::StreamConfig,
+};
+
+/// Bing adult content filter (safeSearch parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum BingSafeSearch {
+    Off,
+    Moderate,
+    Strict,
+}
+
+/// Query for the `bing` engine, and the base of the other Bing verticals.
+///
+/// Market, paging and safe search settings carry over to
+/// [`news`](Self::news), [`images`](Self::images) and
+/// [`shopping`](Self::shopping) queries.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use serp_sdk::{SerpClient, StreamConfig};
+/// use serp_sdk::engines::bing::{BingQuery, BingSafeSearch};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = BingQuery::new("rust async runtime")
+///     .market("en-US")
+///     .safe_search(BingSafeSearch::Strict)
+///     .count(30)?;
+///
+/// let mut results = client.bing_stream(query.clone(), StreamConfig::new().max_pages(2));
+/// while let Some(result) = results.next().await {
+///     println!("{}", result?.link);
+/// }
+///
+/// let news = client.search_engine(query.news()).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct BingQuery {
+    q: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cc: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mkt: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    first: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    count: Option<u32>,
+
+    #[serde(rename = "safeSearch", skip_serializing_if = "Option::is_none")]
+    safe_search: Option<BingSafeSearch>,
+}
+
+impl BingQuery {
+    /// Create a Bing web search
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            q: query.into(),
+            cc: None,
+            mkt: None,
+            first: None,
+            count: None,
+            safe_search: None,
+        }
+    }
+
+    /// Set the country to search from (cc parameter)
+    /// Examples: "us", "gb", "de"
+    pub fn country(mut self, cc: impl Into<String>) -> Self {
+        self.cc = Some(cc.into());
+        self
+    }
+
+    /// Set the market, which takes precedence over the country (mkt parameter)
+    /// Examples: "en-US", "en-GB", "de-DE"
+    pub fn market(mut self, mkt: impl Into<String>) -> Self {
+        self.mkt = Some(mkt.into());
+        self
+    }
+
+    /// Set the one-based offset of the first result (first parameter)
+    pub fn first(mut self, first: u32) -> SerpResult<Self> {
+        if first == 0 {
+            return Err(SerpError::InvalidParameter(
+                "Bing results start at 1".to_string(),
+            ));
+        }
+        self.first = Some(first);
+        Ok(self)
+    }
+
+    /// Set the number of results per page (count parameter)
+    /// Web, news and shopping searches accept up to 50, image searches up to 150
+    pub fn count(mut self, count: u32) -> SerpResult<Self> {
+        if count == 0 || count > 150 {
+            return Err(SerpError::InvalidParameter(
+                "count must be between 1 and 150".to_string(),
+            ));
+        }
+        self.count = Some(count);
+        Ok(self)
+    }
+
+    /// Set the adult content filter (safeSearch parameter)
+    pub fn safe_search(mut self, safe_search: BingSafeSearch) -> Self {
+        self.safe_search = Some(safe_search);
+        self
+    }
+
+    /// Search Bing News with the same settings
+    pub fn news(self) -> BingNewsQuery {
+        BingNewsQuery {
+            base: self,
+            qft: None,
+        }
+    }
+
+    /// Search Bing Images with the same settings
+    pub fn images(self) -> BingImagesQuery {
+        BingImagesQuery {
+            base: self,
+            qft: None,
+        }
+    }
+
+    /// Search Bing Shopping with the same settings
+    pub fn shopping(self) -> BingShoppingQuery {
+        BingShoppingQuery { base: self }
+    }
+
+    fn check_count(&self, max: u32) -> SerpResult<()> {
+        match self.count {
+            Some(count) if count > max => Err(SerpError::InvalidParameter(format!(
+                "count must be at most {} for this Bing engine",
+                max
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Query for the page after one that returned `returned` results
+    fn following(&self, pagination: &Option<SerpapiPagination>, returned: usize) -> Option<Self> {
+        let has_next = has_next_page(pagination);
+        (has_next && returned > 0).then(|| Self {
+            first: Some(self.first.unwrap_or(1) + returned as u32),
+            ..self.clone()
+        })
+    }
+}
+
+impl EngineQuery for BingQuery {
+    type Response = BingResults;
+
+    fn engine(&self) -> &str {
+        "bing"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        self.check_count(50)
+    }
+
+    fn next_page(&self, response: &BingResults) -> Option<Self> {
+        let returned = response.organic_results.as_ref().map_or(0, Vec::len);
+        self.following(&response.serpapi_pagination, returned)
+    }
+}
+
+/// Query for the `bing_news` engine.
+///
+/// Built from a web query with [`BingQuery::news`].
+#[derive(Debug, Clone, Serialize)]
+pub struct BingNewsQuery {
+    #[serde(flatten)]
+    base: BingQuery,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    qft: Option<String>,
+}
+
+impl BingNewsQuery {
+    /// Sort articles by date instead of relevance
+    pub fn sort_by_date(mut self) -> Self {
+        self.qft = Some("sortbydate=\"1\"".to_string());
+        self
+    }
+
+    /// Set a raw news filter (qft parameter)
+    /// Examples: `interval="7"` (past 24 hours), `interval="8"` (past week)
+    pub fn filter(mut self, qft: impl Into<String>) -> Self {
+        self.qft = Some(qft.into());
+        self
+    }
+}
+
+impl EngineQuery for BingNewsQuery {
+    type Response = BingNewsResults;
+
+    fn engine(&self) -> &str {
+        "bing_news"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        self.base.check_count(50)
+    }
+
+    fn next_page(&self, response: &BingNewsResults) -> Option<Self> {
+        let returned = response.organic_results.as_ref().map_or(0, Vec::len);
+        let base = self
+            .base
+            .following(&response.serpapi_pagination, returned)?;
+        Some(Self {
+            base,
+            qft: self.qft.clone(),
+        })
+    }
+}
+
+/// Query for the `bing_images` engine.
+///
+/// Built from a web query with [`BingQuery::images`].
+#[derive(Debug, Clone, Serialize)]
+pub struct BingImagesQuery {
+    #[serde(flatten)]
+    base: BingQuery,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    qft: Option<String>,
+}
+
+impl BingImagesQuery {
+    /// Set a raw image filter (qft parameter)
+    /// Examples: "+filterui:imagesize-large", "+filterui:photo-transparent"
+    pub fn filter(mut self, qft: impl Into<String>) -> Self {
+        self.qft = Some(qft.into());
+        self
+    }
+}
+
+impl EngineQuery for BingImagesQuery {
+    type Response = BingImagesResults;
+
+    fn engine(&self) -> &str {
+        "bing_images"
+    }
+
+    fn next_page(&self, response: &BingImagesResults) -> Option<Self> {
+        let returned = response.images_results.as_ref().map_or(0, Vec::len);
+        let base = self
+            .base
+            .following(&response.serpapi_pagination, returned)?;
+        Some(Self {
+            base,
+            qft: self.qft.clone(),
+        })
+    }
+}
+
+/// Query for the `bing_shopping` engine.
+///
+/// Built from a web query with [`BingQuery::shopping`].
+#[derive(Debug, Clone, Serialize)]
+pub struct BingShoppingQuery {
+    #[serde(flatten)]
+    base: BingQuery,
+}
+
+impl EngineQuery for BingShoppingQuery {
+    type Response = BingShoppingResults;
+
+    fn engine(&self) -> &str {
+        "bing_shopping"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        self.base.check_count(50)
+    }
+
+    fn next_page(&self, response: &BingShoppingResults) -> Option<Self> {
+        let returned = response.shopping_results.as_ref().map_or(0, Vec::len);
+        let base = self
+            .base
+            .following(&response.serpapi_pagination, returned)?;
+        Some(Self { base })
+    }
+}
+
+impl SerpClient {
+    /// Stream individual Bing web results across pages
+    ///
+    /// Pages are requested with an increasing `first` offset until SerpAPI stops
+    /// returning a next page or `config.max_pages` is reached.
+    pub fn bing_stream(
+        &self,
+        query: BingQuery,
+        config: StreamConfig,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<OrganicResult>> + Send + '_>> {
+        self.engine_items_stream(query, config, |page| {
+            page.organic_results.unwrap_or_default()
+        })
+    }
+
+    /// Stream individual Bing News articles across pages
+    pub fn bing_news_stream(
+        &self,
+        query: BingNewsQuery,
+        config: StreamConfig,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<NewsResult>> + Send + '_>> {
+        self.engine_items_stream(query, config, |page| {
+            page.organic_results.unwrap_or_default()
+        })
+    }
+
+    /// Stream individual Bing Images results across pages
+    pub fn bing_images_stream(
+        &self,
+        query: BingImagesQuery,
+        config: StreamConfig,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<ImageResult>> + Send + '_>> {
+        self.engine_items_stream(query, config, |page| {
+            page.images_results.unwrap_or_default()
+        })
+    }
+}
+
+/// Results of a `bing` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct BingResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub search_information: Option<BingSearchInformation>,
+    pub organic_results: Option<Vec<OrganicResult>>,
+    pub knowledge_graph: Option<KnowledgeGraph>,
+    pub related_searches: Option<Vec<RelatedSearch>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Search information reported by Bing
+#[derive(Debug, Deserialize, Clone)]
+pub struct BingSearchInformation {
+    pub query_displayed: Option<String>,
+    pub total_results: Option<u64>,
+}
+
+/// Results of a `bing_news` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct BingNewsResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub organic_results: Option<Vec<NewsResult>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Results of a `bing_images` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct BingImagesResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub images_results: Option<Vec<ImageResult>>,
+    pub related_searches: Option<Vec<RelatedSearch>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Results of a `bing_shopping` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct BingShoppingResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub shopping_results: Option<Vec<ShoppingResult>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bing_query_params() {
+        let query = BingQuery::new("coffee")
+            .market("en-US")
+            .safe_search(BingSafeSearch::Strict)
+            .count(20)
+            .unwrap();
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("q".to_string(), "coffee".to_string()),
+                ("mkt".to_string(), "en-US".to_string()),
+                ("count".to_string(), "20".to_string()),
+                ("safeSearch".to_string(), "Strict".to_string()),
+            ]
+        );
+
+        let news = query.clone().news().sort_by_date();
+        assert_eq!(news.engine(), "bing_news");
+        assert!(news
+            .params()
+            .unwrap()
+            .contains(&("qft".to_string(), "sortbydate=\"1\"".to_string())));
+
+        let wide = query.count(100).unwrap();
+        assert!(wide.validate().is_err());
+        assert!(wide.images().validate().is_ok());
+    }
+
+    #[test]
+    fn test_bing_results_and_next_page() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "search_information": {"query_displayed": "coffee", "total_results": 1200000},
+            "organic_results": [
+                {"position": 1, "title": "Coffee", "link": "https://en.wikipedia.org/wiki/Coffee", "snippet": "Coffee is a beverage..."},
+                {"position": 2, "title": "Starbucks", "link": "https://www.starbucks.com/"}
+            ],
+            "serpapi_pagination": {"current": 1, "next": "https://serpapi.com/search.json?first=3"}
+        }"#;
+        let results: BingResults = serde_json::from_str(json).unwrap();
+        assert_eq!(results.organic_results.as_ref().unwrap()[0].title, "Coffee");
+
+        let next = BingQuery::new("coffee").next_page(&results).unwrap();
+        assert_eq!(next.first, Some(3));
+        let after = next.next_page(&results).unwrap();
+        assert_eq!(after.first, Some(5));
+    }
+}
@@ -0,0 +1,210 @@
+#![allow(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    engines::{has_next_page, EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    parse,
+    response::{KnowledgeGraph, OrganicResult, RelatedSearch, SearchMetadata, SerpapiPagination},
+};
+
+/// Age of DuckDuckGo results (df parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuckDuckGoDate {
+    PastDay,
+    PastWeek,
+    PastMonth,
+    PastYear,
+}
+
+impl DuckDuckGoDate {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::PastDay => "d",
+            Self::PastWeek => "w",
+            Self::PastMonth => "m",
+            Self::PastYear => "y",
+        }
+    }
+}
+
+/// DuckDuckGo adult content filter (safe parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuckDuckGoSafeSearch {
+    Strict,
+    Moderate,
+    Off,
+}
+
+/// Query for the `duckduckgo` engine.
+///
+/// Pages are reached with a `start` offset advanced by the number of results
+/// each page returned.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use serp_sdk::{SerpClient, StreamConfig};
+/// use serp_sdk::engines::duckduckgo::{DuckDuckGoDate, DuckDuckGoQuery};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = DuckDuckGoQuery::new("coffee roasters")
+///     .region("uk-en")
+///     .date(DuckDuckGoDate::PastMonth);
+///
+/// let mut pages = client.engine_stream(query, StreamConfig::new().max_pages(3));
+/// while let Some(page) = pages.next().await {
+///     for result in page?.organic_results.unwrap_or_default() {
+///         println!("{:?} {}", result.position, result.link);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct DuckDuckGoQuery {
+    q: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kl: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    df: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    safe: Option<i8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start: Option<u32>,
+}
+
+impl DuckDuckGoQuery {
+    /// Create a DuckDuckGo search
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            q: query.into(),
+            kl: None,
+            df: None,
+            safe: None,
+            start: None,
+        }
+    }
+
+    /// Set the region (kl parameter)
+    /// Examples: "us-en", "uk-en", "de-de", "wt-wt" (no region)
+    pub fn region(mut self, kl: impl Into<String>) -> Self {
+        self.kl = Some(kl.into());
+        self
+    }
+
+    /// Only include recent results (df parameter)
+    pub fn date(mut self, date: DuckDuckGoDate) -> Self {
+        self.df = Some(date.as_str().to_string());
+        self
+    }
+
+    /// Only include results published between two "YYYY-MM-DD" dates (df parameter)
+    pub fn date_range(mut self, from: &str, to: &str) -> SerpResult<Self> {
+        if !parse::is_iso_date(from) || !parse::is_iso_date(to) {
+            return Err(SerpError::InvalidParameter(
+                "date range must be formatted as YYYY-MM-DD".to_string(),
+            ));
+        }
+        if from > to {
+            return Err(SerpError::InvalidParameter(
+                "start date must not be after end date".to_string(),
+            ));
+        }
+        self.df = Some(format!("{}..{}", from, to));
+        Ok(self)
+    }
+
+    /// Set the adult content filter (safe parameter)
+    pub fn safe_search(mut self, safe: DuckDuckGoSafeSearch) -> Self {
+        self.safe = Some(match safe {
+            DuckDuckGoSafeSearch::Strict => 1,
+            DuckDuckGoSafeSearch::Moderate => -1,
+            DuckDuckGoSafeSearch::Off => -2,
+        });
+        self
+    }
+
+    /// Set the offset for pagination (start parameter)
+    pub fn offset(mut self, start: u32) -> Self {
+        self.start = Some(start);
+        self
+    }
+}
+
+impl EngineQuery for DuckDuckGoQuery {
+    type Response = DuckDuckGoResults;
+
+    fn engine(&self) -> &str {
+        "duckduckgo"
+    }
+
+    fn next_page(&self, response: &DuckDuckGoResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        let returned = response.organic_results.as_ref().map_or(0, Vec::len) as u32;
+        (has_next && returned > 0).then(|| {
+            let start = self.start.unwrap_or(0) + returned;
+            self.clone().offset(start)
+        })
+    }
+}
+
+/// Results of a `duckduckgo` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct DuckDuckGoResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub organic_results: Option<Vec<OrganicResult>>,
+    pub knowledge_graph: Option<KnowledgeGraph>,
+    pub related_searches: Option<Vec<RelatedSearch>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duckduckgo_query_params() {
+        let query = DuckDuckGoQuery::new("coffee")
+            .region("us-en")
+            .date_range("2024-01-01", "2024-02-01")
+            .unwrap()
+            .safe_search(DuckDuckGoSafeSearch::Off);
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("q".to_string(), "coffee".to_string()),
+                ("kl".to_string(), "us-en".to_string()),
+                ("df".to_string(), "2024-01-01..2024-02-01".to_string()),
+                ("safe".to_string(), "-2".to_string()),
+            ]
+        );
+        assert!(DuckDuckGoQuery::new("coffee")
+            .date_range("2024-02-01", "2024-01-01")
+            .is_err());
+    }
+
+    #[test]
+    fn test_duckduckgo_next_page() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "organic_results": [
+                {"position": 1, "title": "Coffee", "link": "https://en.wikipedia.org/wiki/Coffee"},
+                {"position": 2, "title": "Coffee beans", "link": "https://example.com/beans"}
+            ],
+            "serpapi_pagination": {"next": "https://serpapi.com/search.json?start=2"}
+        }"#;
+        let results: DuckDuckGoResults = serde_json::from_str(json).unwrap();
+        let next = DuckDuckGoQuery::new("coffee").next_page(&results).unwrap();
+        assert_eq!(next.start, Some(2));
+    }
+}
@@ -0,0 +1,340 @@
+#![allow(missing_docs)]
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+
+use crate::{
+    client::SerpClient,
+    engines::{check_price_range, has_next_page, EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    response::{ListingSummary, Price, ProductListing, SearchMetadata, SerpapiPagination},
+    streaming::StreamConfig,
+};
+
+/// Sort order for eBay results (_sop parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EbaySort {
+    BestMatch,
+    EndingSoonest,
+    NewlyListed,
+    PriceLowest,
+    PriceHighest,
+}
+
+/// Item condition filter (LH_ItemCondition parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EbayCondition {
+    New,
+    OpenBox,
+    Refurbished,
+    Used,
+    ForParts,
+}
+
+/// Query for the `ebay` engine.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use serp_sdk::{SerpClient, StreamConfig};
+/// use serp_sdk::engines::ebay::{EbayCondition, EbayQuery, EbaySort};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = EbayQuery::new("thinkpad x1 carbon")
+///     .condition(EbayCondition::Used)
+///     .price_range(Some(200.0), Some(600.0))?
+///     .sort_by(EbaySort::PriceLowest);
+///
+/// let mut items = client.ebay_stream(query, StreamConfig::new().max_pages(3));
+/// while let Some(item) = items.next().await {
+///     let item = item?;
+///     println!("{} {:?}", item.title, item.price);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct EbayQuery {
+    #[serde(rename = "_nkw")]
+    keywords: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ebay_domain: Option<String>,
+
+    #[serde(rename = "_sop", skip_serializing_if = "Option::is_none")]
+    sort: Option<u8>,
+
+    #[serde(rename = "_udlo", skip_serializing_if = "Option::is_none")]
+    min_price: Option<f64>,
+
+    #[serde(rename = "_udhi", skip_serializing_if = "Option::is_none")]
+    max_price: Option<f64>,
+
+    #[serde(rename = "LH_ItemCondition", skip_serializing_if = "Option::is_none")]
+    condition: Option<u16>,
+
+    #[serde(rename = "LH_BIN", skip_serializing_if = "Option::is_none")]
+    buy_it_now: Option<u8>,
+
+    #[serde(rename = "_ipg", skip_serializing_if = "Option::is_none")]
+    per_page: Option<u32>,
+
+    #[serde(rename = "_pgn", skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl EbayQuery {
+    /// Create an eBay search (_nkw parameter)
+    pub fn new(keywords: impl Into<String>) -> Self {
+        Self {
+            keywords: keywords.into(),
+            ebay_domain: None,
+            sort: None,
+            min_price: None,
+            max_price: None,
+            condition: None,
+            buy_it_now: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Set the eBay domain (ebay_domain parameter)
+    /// Examples: "ebay.com", "ebay.co.uk", "ebay.de"
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.ebay_domain = Some(domain.into());
+        self
+    }
+
+    /// Set the sort order (_sop parameter)
+    pub fn sort_by(mut self, sort: EbaySort) -> Self {
+        self.sort = Some(match sort {
+            EbaySort::BestMatch => 12,
+            EbaySort::EndingSoonest => 1,
+            EbaySort::NewlyListed => 10,
+            EbaySort::PriceLowest => 15,
+            EbaySort::PriceHighest => 16,
+        });
+        self
+    }
+
+    /// Only include items priced within a range (_udlo/_udhi parameters)
+    pub fn price_range(mut self, min: Option<f64>, max: Option<f64>) -> SerpResult<Self> {
+        check_price_range(min, max)?;
+        self.min_price = min;
+        self.max_price = max;
+        Ok(self)
+    }
+
+    /// Only include items in a given condition (LH_ItemCondition parameter)
+    pub fn condition(mut self, condition: EbayCondition) -> Self {
+        self.condition = Some(match condition {
+            EbayCondition::New => 1000,
+            EbayCondition::OpenBox => 1500,
+            EbayCondition::Refurbished => 2500,
+            EbayCondition::Used => 3000,
+            EbayCondition::ForParts => 7000,
+        });
+        self
+    }
+
+    /// Only include fixed-price listings (LH_BIN parameter)
+    pub fn buy_it_now(mut self) -> Self {
+        self.buy_it_now = Some(1);
+        self
+    }
+
+    /// Set the number of items per page (_ipg parameter)
+    /// Values: 25, 50, 100, 200
+    pub fn limit(mut self, per_page: u32) -> SerpResult<Self> {
+        if ![25, 50, 100, 200].contains(&per_page) {
+            return Err(SerpError::InvalidParameter(
+                "limit must be 25, 50, 100 or 200".to_string(),
+            ));
+        }
+        self.per_page = Some(per_page);
+        Ok(self)
+    }
+
+    /// Set the one-based page number (_pgn parameter)
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+impl EngineQuery for EbayQuery {
+    type Response = EbayResults;
+
+    fn engine(&self) -> &str {
+        "ebay"
+    }
+
+    fn next_page(&self, response: &EbayResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        has_next.then(|| {
+            let page = self.page.unwrap_or(1) + 1;
+            self.clone().page(page)
+        })
+    }
+}
+
+impl SerpClient {
+    /// Stream individual eBay listings across pages
+    pub fn ebay_stream(
+        &self,
+        query: EbayQuery,
+        config: StreamConfig,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<EbayItem>> + Send + '_>> {
+        self.engine_items_stream(query, config, |page| {
+            page.organic_results.unwrap_or_default()
+        })
+    }
+}
+
+/// Results of an `ebay` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct EbayResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub organic_results: Option<Vec<EbayItem>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// eBay listing
+#[derive(Debug, Deserialize, Clone)]
+pub struct EbayItem {
+    pub position: Option<u32>,
+    pub product_id: Option<String>,
+    pub title: String,
+    pub link: Option<String>,
+    pub thumbnail: Option<String>,
+    pub condition: Option<String>,
+    pub price: Option<EbayPrice>,
+    pub shipping: Option<String>,
+    pub location: Option<String>,
+    pub time_left: Option<String>,
+    pub buying_format: Option<String>,
+    pub rating: Option<f32>,
+    pub reviews: Option<u64>,
+    pub seller: Option<EbaySeller>,
+}
+
+impl ProductListing for EbayItem {
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn link(&self) -> Option<&str> {
+        self.link.as_deref()
+    }
+
+    /// The price is the lower bound for listings with a price range
+    fn summary(&self) -> ListingSummary {
+        ListingSummary {
+            price: self.price.as_ref().and_then(EbayPrice::lowest),
+            old_price: None,
+            rating: self.rating,
+            reviews: self.reviews,
+            seller: self.seller.as_ref().map(|s| s.username.clone()),
+        }
+    }
+}
+
+/// Single price or a price range of a listing with variants
+#[derive(Debug, Deserialize, Clone)]
+pub struct EbayPrice {
+    pub raw: Option<String>,
+    pub extracted: Option<f64>,
+    pub from: Option<EbayAmount>,
+    pub to: Option<EbayAmount>,
+}
+
+impl EbayPrice {
+    fn lowest(&self) -> Option<Price> {
+        Price::from_parts(self.raw.as_deref(), self.extracted)
+            .or_else(|| self.from.as_ref()?.price())
+    }
+}
+
+/// Displayed and extracted amount
+#[derive(Debug, Deserialize, Clone)]
+pub struct EbayAmount {
+    pub raw: Option<String>,
+    pub extracted: Option<f64>,
+}
+
+impl EbayAmount {
+    fn price(&self) -> Option<Price> {
+        Price::from_parts(self.raw.as_deref(), self.extracted)
+    }
+}
+
+/// eBay seller
+#[derive(Debug, Deserialize, Clone)]
+pub struct EbaySeller {
+    pub username: String,
+    pub reviews: Option<u64>,
+    pub positive_feedback_in_percentage: Option<f32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ebay_query_params() {
+        let query = EbayQuery::new("thinkpad")
+            .sort_by(EbaySort::PriceLowest)
+            .price_range(Some(200.0), None)
+            .unwrap()
+            .condition(EbayCondition::Used)
+            .page(3);
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("_nkw".to_string(), "thinkpad".to_string()),
+                ("_sop".to_string(), "15".to_string()),
+                ("_udlo".to_string(), "200.0".to_string()),
+                ("LH_ItemCondition".to_string(), "3000".to_string()),
+                ("_pgn".to_string(), "3".to_string()),
+            ]
+        );
+        assert!(EbayQuery::new("thinkpad").limit(30).is_err());
+    }
+
+    #[test]
+    fn test_ebay_results_and_summary() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "organic_results": [
+                {
+                    "position": 1,
+                    "title": "Lenovo ThinkPad X1 Carbon",
+                    "link": "https://www.ebay.com/itm/1",
+                    "price": {"raw": "$349.99", "extracted": 349.99},
+                    "seller": {"username": "laptopdeals", "reviews": 5400, "positive_feedback_in_percentage": 99.6}
+                },
+                {
+                    "position": 2,
+                    "title": "ThinkPad batteries",
+                    "price": {"from": {"raw": "$20.00", "extracted": 20.0}, "to": {"raw": "$45.00", "extracted": 45.0}}
+                }
+            ],
+            "serpapi_pagination": {"next": "https://serpapi.com/search.json?_pgn=2"}
+        }"#;
+        let results: EbayResults = serde_json::from_str(json).unwrap();
+        let items = results.organic_results.as_ref().unwrap();
+        let summary = items[0].summary();
+        assert_eq!(summary.price.unwrap().currency.as_deref(), Some("USD"));
+        assert_eq!(summary.seller.as_deref(), Some("laptopdeals"));
+        assert_eq!(items[1].summary().price.unwrap().amount, 20.0);
+
+        let next = EbayQuery::new("thinkpad").next_page(&results).unwrap();
+        assert_eq!(next.page, Some(2));
+    }
+}
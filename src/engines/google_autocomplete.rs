@@ -0,0 +1,298 @@
+#![allow(missing_docs)]
+
+use futures::stream::{self, StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::{
+    client::SerpClient,
+    engines::{EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    response::SearchMetadata,
+};
+
+/// Question words used by [`KeywordExpansion::questions`]
+const QUESTION_PREFIXES: &[&str] = &[
+    "what", "how", "why", "when", "where", "who", "which", "can", "is", "are",
+];
+
+/// Query for the `google_autocomplete` engine.
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleAutocompleteQuery {
+    q: String,
+
+    #[serde(rename = "gl", skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cp: Option<usize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client: Option<String>,
+}
+
+impl GoogleAutocompleteQuery {
+    /// Create an autocomplete query for a partial search
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            q: query.into(),
+            country: None,
+            language: None,
+            cp: None,
+            client: None,
+        }
+    }
+
+    /// Set the country (gl parameter)
+    pub fn country(mut self, gl: impl Into<String>) -> Self {
+        self.country = Some(gl.into());
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Set the cursor position within the query (cp parameter)
+    pub fn cursor_position(mut self, cp: usize) -> SerpResult<Self> {
+        if cp > self.q.chars().count() {
+            return Err(SerpError::InvalidParameter(
+                "cursor position is past the end of the query".to_string(),
+            ));
+        }
+        self.cp = Some(cp);
+        Ok(self)
+    }
+
+    /// Set the client to emulate (client parameter)
+    /// Common values: "chrome", "firefox", "safari", "youtube"
+    pub fn client(mut self, client: impl Into<String>) -> Self {
+        self.client = Some(client.into());
+        self
+    }
+
+    /// Get the partial search
+    pub fn query(&self) -> &str {
+        &self.q
+    }
+
+    fn with_query(&self, q: String) -> Self {
+        Self {
+            q,
+            cp: None,
+            ..self.clone()
+        }
+    }
+}
+
+impl EngineQuery for GoogleAutocompleteQuery {
+    type Response = GoogleAutocompleteResults;
+
+    fn engine(&self) -> &str {
+        "google_autocomplete"
+    }
+}
+
+/// Results of a `google_autocomplete` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleAutocompleteResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub suggestions: Option<Vec<AutocompleteSuggestion>>,
+    pub verbatim_relevance: Option<u32>,
+}
+
+/// Autocomplete suggestion
+#[derive(Debug, Deserialize, Clone)]
+pub struct AutocompleteSuggestion {
+    pub value: String,
+    pub relevance: Option<u32>,
+    /// e.g. "QUERY" or "ENTITY"
+    #[serde(rename = "type")]
+    pub suggestion_type: Option<String>,
+    pub serpapi_link: Option<String>,
+}
+
+/// Settings for [`SerpClient::expand_keywords`]
+#[derive(Debug, Clone)]
+pub struct KeywordExpansion {
+    alphabet: bool,
+    questions: bool,
+    prefixes: Vec<String>,
+    concurrency: usize,
+}
+
+impl Default for KeywordExpansion {
+    fn default() -> Self {
+        Self {
+            alphabet: true,
+            questions: true,
+            prefixes: Vec::new(),
+            concurrency: 4,
+        }
+    }
+}
+
+impl KeywordExpansion {
+    /// Create the default expansion (alphabet and question prefixes, 4 requests at a time)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expand with "seed a" through "seed z"
+    pub fn alphabet(mut self, enabled: bool) -> Self {
+        self.alphabet = enabled;
+        self
+    }
+
+    /// Expand with question words ("what seed", "how seed", ...)
+    pub fn questions(mut self, enabled: bool) -> Self {
+        self.questions = enabled;
+        self
+    }
+
+    /// Expand with a custom prefix ("best" gives "best seed")
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefixes.push(prefix.into());
+        self
+    }
+
+    /// Set the number of autocomplete requests in flight at once (1-16)
+    pub fn concurrency(mut self, concurrency: usize) -> SerpResult<Self> {
+        if concurrency == 0 || concurrency > 16 {
+            return Err(SerpError::InvalidParameter(
+                "concurrency must be between 1 and 16".to_string(),
+            ));
+        }
+        self.concurrency = concurrency;
+        Ok(self)
+    }
+
+    /// Partial searches to request for a seed, starting with the seed itself
+    fn variants(&self, seed: &str) -> Vec<String> {
+        let seed = seed.trim();
+        let mut variants = vec![seed.to_string()];
+        if self.alphabet {
+            variants.extend(('a'..='z').map(|letter| format!("{} {}", seed, letter)));
+        }
+        if self.questions {
+            variants.extend(QUESTION_PREFIXES.iter().map(|q| format!("{} {}", q, seed)));
+        }
+        variants.extend(
+            self.prefixes
+                .iter()
+                .map(|p| format!("{} {}", p.trim(), seed)),
+        );
+        variants
+    }
+}
+
+impl SerpClient {
+    /// Expand a seed keyword into autocomplete suggestions
+    ///
+    /// Requests suggestions for the seed and each prefixed variant, up to
+    /// `expansion`'s concurrency at a time, and returns them in request order
+    /// with duplicates (ignoring case) removed. Country, language and client
+    /// settings of `seed` apply to every request. Each request goes through the
+    /// client's retry and rate limit handling; the first failure is returned
+    /// and no further variants are requested.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serp_sdk::SerpClient;
+    /// use serp_sdk::engines::google_autocomplete::{GoogleAutocompleteQuery, KeywordExpansion};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SerpClient::new("test")?;
+    /// let keywords = client
+    ///     .expand_keywords(
+    ///         GoogleAutocompleteQuery::new("rust async").country("us"),
+    ///         KeywordExpansion::new().prefix("best"),
+    ///     )
+    ///     .await?;
+    ///
+    /// for keyword in keywords {
+    ///     println!("{}", keyword.value);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn expand_keywords(
+        &self,
+        seed: GoogleAutocompleteQuery,
+        expansion: KeywordExpansion,
+    ) -> SerpResult<Vec<AutocompleteSuggestion>> {
+        let queries: Vec<_> = expansion
+            .variants(seed.query())
+            .into_iter()
+            .map(|variant| seed.with_query(variant))
+            .collect();
+
+        let pages: Vec<GoogleAutocompleteResults> = stream::iter(queries)
+            .map(|query| Ok::<_, SerpError>(self.search_engine(query)))
+            .try_buffered(expansion.concurrency)
+            .try_collect()
+            .await?;
+
+        let suggestions = pages
+            .into_iter()
+            .flat_map(|page| page.suggestions.unwrap_or_default())
+            .collect();
+        Ok(dedup_suggestions(suggestions))
+    }
+}
+
+/// Remove repeated suggestions, keeping the first occurrence
+fn dedup_suggestions(suggestions: Vec<AutocompleteSuggestion>) -> Vec<AutocompleteSuggestion> {
+    let mut seen = HashSet::new();
+    suggestions
+        .into_iter()
+        .filter(|s| seen.insert(s.value.trim().to_lowercase()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expansion_variants() {
+        let variants = KeywordExpansion::new()
+            .questions(false)
+            .prefix("best")
+            .variants(" rust ");
+        assert_eq!(variants.len(), 1 + 26 + 1);
+        assert_eq!(variants[0], "rust");
+        assert_eq!(variants[1], "rust a");
+        assert_eq!(variants[27], "best rust");
+
+        let questions = KeywordExpansion::new().alphabet(false).variants("rust");
+        assert_eq!(questions[1], "what rust");
+        assert!(KeywordExpansion::new().concurrency(0).is_err());
+    }
+
+    #[test]
+    fn test_autocomplete_parsing_and_dedup() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "suggestions": [
+                {"value": "rust lang", "relevance": 601, "type": "QUERY"},
+                {"value": "Rust Lang", "relevance": 550, "type": "QUERY"},
+                {"value": "rust game", "relevance": 500, "type": "QUERY"}
+            ],
+            "verbatim_relevance": 1300
+        }"#;
+        let results: GoogleAutocompleteResults = serde_json::from_str(json).unwrap();
+        let deduped = dedup_suggestions(results.suggestions.unwrap());
+        let values: Vec<_> = deduped.iter().map(|s| s.value.as_str()).collect();
+        assert_eq!(values, vec!["rust lang", "rust game"]);
+        assert_eq!(deduped[0].relevance, Some(601));
+    }
+}
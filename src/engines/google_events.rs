@@ -0,0 +1,320 @@
+#![allow(missing_docs)]
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+
+use crate::{
+    client::SerpClient,
+    engines::{has_next_page, EngineParameters, EngineQuery},
+    error::SerpResult,
+    response::{GpsCoordinates, SearchMetadata, SerpapiPagination},
+    streaming::StreamConfig,
+};
+
+/// Date filter for events (htichips parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventDate {
+    Today,
+    Tomorrow,
+    ThisWeek,
+    ThisWeekend,
+    NextWeek,
+    ThisMonth,
+    NextMonth,
+}
+
+impl EventDate {
+    fn chip(self) -> &'static str {
+        match self {
+            Self::Today => "date:today",
+            Self::Tomorrow => "date:tomorrow",
+            Self::ThisWeek => "date:week",
+            Self::ThisWeekend => "date:weekend",
+            Self::NextWeek => "date:next_week",
+            Self::ThisMonth => "date:month",
+            Self::NextMonth => "date:next_month",
+        }
+    }
+}
+
+/// Query for the `google_events` engine.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use serp_sdk::{SerpClient, StreamConfig};
+/// use serp_sdk::engines::google_events::{EventDate, GoogleEventsQuery};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = GoogleEventsQuery::new("concerts")
+///     .location("Austin, Texas, United States")
+///     .date(EventDate::ThisWeekend);
+///
+/// let mut events = client.events_stream(query, StreamConfig::new().max_pages(2));
+/// while let Some(event) = events.next().await {
+///     let event = event?;
+///     println!("{} at {:?}", event.title, event.venue.map(|v| v.name));
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleEventsQuery {
+    q: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<String>,
+
+    #[serde(rename = "gl", skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    htichips: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start: Option<u32>,
+}
+
+impl GoogleEventsQuery {
+    /// Create an events search (e.g. "concerts in Austin")
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            q: query.into(),
+            location: None,
+            country: None,
+            language: None,
+            htichips: None,
+            start: None,
+        }
+    }
+
+    /// Set the location to search from (location parameter)
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    /// Set the country (gl parameter)
+    pub fn country(mut self, gl: impl Into<String>) -> Self {
+        self.country = Some(gl.into());
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Only include events on the given dates, replacing any earlier date filter
+    pub fn date(mut self, date: EventDate) -> Self {
+        if let Some(chips) = self.htichips.take() {
+            let kept: Vec<&str> = chips
+                .split(',')
+                .filter(|chip| !chip.starts_with("date:"))
+                .collect();
+            self.htichips = (!kept.is_empty()).then(|| kept.join(","));
+        }
+        self.chip(date.chip())
+    }
+
+    /// Only include online events
+    pub fn online_only(self) -> Self {
+        self.chip("event_type:Virtual-Event")
+    }
+
+    /// Add a raw filter chip (htichips parameter)
+    pub fn chip(mut self, chip: impl Into<String>) -> Self {
+        let chip = chip.into();
+        self.htichips = Some(match self.htichips.take() {
+            Some(chips) => format!("{},{}", chips, chip),
+            None => chip,
+        });
+        self
+    }
+
+    /// Set the offset for pagination (start parameter)
+    pub fn offset(mut self, start: u32) -> Self {
+        self.start = Some(start);
+        self
+    }
+}
+
+impl EngineQuery for GoogleEventsQuery {
+    type Response = GoogleEventsResults;
+
+    fn engine(&self) -> &str {
+        "google_events"
+    }
+
+    fn next_page(&self, response: &GoogleEventsResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        let returned = response.events_results.as_ref().map_or(0, Vec::len) as u32;
+        (has_next && returned > 0).then(|| {
+            let start = self.start.unwrap_or(0) + returned;
+            self.clone().offset(start)
+        })
+    }
+}
+
+impl SerpClient {
+    /// Stream individual Google Events results across pages
+    pub fn events_stream(
+        &self,
+        query: GoogleEventsQuery,
+        config: StreamConfig,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<EventResult>> + Send + '_>> {
+        self.engine_items_stream(query, config, |page| {
+            page.events_results.unwrap_or_default()
+        })
+    }
+}
+
+/// Results of a `google_events` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleEventsResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub events_results: Option<Vec<EventResult>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Event listing
+#[derive(Debug, Deserialize, Clone)]
+pub struct EventResult {
+    pub title: String,
+    pub date: Option<EventDateRange>,
+    /// Venue name followed by address lines
+    pub address: Option<Vec<String>>,
+    pub gps_coordinates: Option<GpsCoordinates>,
+    pub link: Option<String>,
+    pub description: Option<String>,
+    pub ticket_info: Option<Vec<TicketInfo>>,
+    pub venue: Option<EventVenue>,
+    pub event_location_map: Option<EventLocationMap>,
+    pub thumbnail: Option<String>,
+    pub image: Option<String>,
+}
+
+impl EventResult {
+    /// Address as a single line, like the `address` of a [`LocalPlace`](crate::response::LocalPlace)
+    pub fn address_line(&self) -> Option<String> {
+        self.address.as_ref().map(|lines| lines.join(", "))
+    }
+}
+
+/// When an event takes place
+///
+/// Google shows dates without a year and times in free-form text, so only the
+/// start month and day are parsed; `when` is kept as displayed.
+#[derive(Debug, Deserialize, Clone)]
+pub struct EventDateRange {
+    /// e.g. "Dec 7"
+    pub start_date: Option<String>,
+    /// e.g. "Sat, Dec 7, 8 – 11 PM"
+    pub when: Option<String>,
+}
+
+impl EventDateRange {
+    /// Start date as (month, day), e.g. (12, 7) for "Dec 7"
+    pub fn start_month_day(&self) -> Option<(u32, u32)> {
+        const MONTHS: [&str; 12] = [
+            "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+        ];
+        let (month, day) = self.start_date.as_deref()?.trim().split_once(' ')?;
+        let month = month.get(..3)?.to_ascii_lowercase();
+        let month = MONTHS.iter().position(|m| *m == month)? as u32 + 1;
+        let day: u32 = day.trim().parse().ok()?;
+        (1..=31).contains(&day).then_some((month, day))
+    }
+}
+
+/// Where to buy tickets or find more information
+#[derive(Debug, Deserialize, Clone)]
+pub struct TicketInfo {
+    pub source: Option<String>,
+    pub link: Option<String>,
+    /// "tickets" or "more info"
+    pub link_type: Option<String>,
+}
+
+/// Venue hosting an event
+#[derive(Debug, Deserialize, Clone)]
+pub struct EventVenue {
+    pub name: String,
+    pub rating: Option<f64>,
+    pub reviews: Option<u32>,
+    pub link: Option<String>,
+}
+
+/// Map of an event's location
+#[derive(Debug, Deserialize, Clone)]
+pub struct EventLocationMap {
+    pub image: Option<String>,
+    pub link: Option<String>,
+    pub serpapi_link: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_events_query_params() {
+        let query = GoogleEventsQuery::new("concerts")
+            .location("Austin, Texas, United States")
+            .date(EventDate::Today)
+            .online_only()
+            .date(EventDate::ThisWeekend);
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("q".to_string(), "concerts".to_string()),
+                (
+                    "location".to_string(),
+                    "Austin, Texas, United States".to_string()
+                ),
+                (
+                    "htichips".to_string(),
+                    "event_type:Virtual-Event,date:weekend".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_events_results_parsing() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "events_results": [{
+                "title": "Jazz Night",
+                "date": {"start_date": "Dec 7", "when": "Sat, Dec 7, 8 – 11 PM"},
+                "address": ["Elephant Room", "315 Congress Ave, Austin, TX"],
+                "ticket_info": [{"source": "Ticketmaster", "link": "https://tm.example/1", "link_type": "tickets"}],
+                "venue": {"name": "Elephant Room", "rating": 4.7, "reviews": 900}
+            }],
+            "serpapi_pagination": {"next": "https://serpapi.com/search.json?start=10"}
+        }"#;
+        let results: GoogleEventsResults = serde_json::from_str(json).unwrap();
+        let event = &results.events_results.as_ref().unwrap()[0];
+        assert_eq!(
+            event.address_line().as_deref(),
+            Some("Elephant Room, 315 Congress Ave, Austin, TX")
+        );
+        assert_eq!(event.venue.as_ref().unwrap().reviews, Some(900));
+        assert_eq!(
+            event.date.as_ref().unwrap().start_month_day(),
+            Some((12, 7))
+        );
+
+        let next = GoogleEventsQuery::new("jazz").next_page(&results).unwrap();
+        assert_eq!(next.start, Some(1));
+    }
+}
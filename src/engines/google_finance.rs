@@ -0,0 +1,467 @@
+#![allow(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    engines::{EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    parse,
+    response::{PriceMovement, SearchMetadata},
+};
+
+/// Time window of the price graph (window parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum FinanceWindow {
+    #[serde(rename = "1D")]
+    OneDay,
+    #[serde(rename = "5D")]
+    FiveDays,
+    #[serde(rename = "1M")]
+    OneMonth,
+    #[serde(rename = "6M")]
+    SixMonths,
+    #[serde(rename = "YTD")]
+    YearToDate,
+    #[serde(rename = "1Y")]
+    OneYear,
+    #[serde(rename = "5Y")]
+    FiveYears,
+    #[serde(rename = "MAX")]
+    Max,
+}
+
+/// Query for the `google_finance` engine.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::SerpClient;
+/// use serp_sdk::engines::google_finance::{FinanceWindow, GoogleFinanceQuery};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = GoogleFinanceQuery::ticker("AAPL", "NASDAQ").window(FinanceWindow::OneMonth);
+///
+/// let results = client.search_engine(query).await?;
+/// if let Some(summary) = &results.summary {
+///     println!("{}: {:?}", summary.title, summary.extracted_price);
+/// }
+/// for point in results.graph.unwrap_or_default() {
+///     println!("{} {}", point.date, point.price);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleFinanceQuery {
+    q: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    window: Option<FinanceWindow>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+}
+
+impl GoogleFinanceQuery {
+    /// Create a query for a ticker, currency pair or index (e.g. "GOOGL:NASDAQ", "BTC-USD")
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            q: query.into(),
+            window: None,
+            language: None,
+        }
+    }
+
+    /// Create a query for a symbol on an exchange ("AAPL", "NASDAQ" gives "AAPL:NASDAQ")
+    pub fn ticker(symbol: &str, exchange: &str) -> Self {
+        Self::new(format!("{}:{}", symbol, exchange))
+    }
+
+    /// Set the time window of the price graph
+    pub fn window(mut self, window: FinanceWindow) -> Self {
+        self.window = Some(window);
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+}
+
+impl EngineQuery for GoogleFinanceQuery {
+    type Response = GoogleFinanceResults;
+
+    fn engine(&self) -> &str {
+        "google_finance"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        if self.q.trim().is_empty() {
+            return Err(SerpError::InvalidParameter(
+                "finance query cannot be empty".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Market overview to fetch (trend parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MarketTrend {
+    Indexes,
+    MostActive,
+    Gainers,
+    Losers,
+    ClimateLeaders,
+    Cryptocurrencies,
+    Currencies,
+}
+
+/// Region of market indexes (index_market parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IndexMarket {
+    Americas,
+    EuropeMiddleEastAfrica,
+    AsiaPacific,
+}
+
+/// Query for the `google_finance_markets` engine.
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleFinanceMarketsQuery {
+    trend: MarketTrend,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index_market: Option<IndexMarket>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+}
+
+impl GoogleFinanceMarketsQuery {
+    /// Create a query for a market overview
+    pub fn new(trend: MarketTrend) -> Self {
+        Self {
+            trend,
+            index_market: None,
+            language: None,
+        }
+    }
+
+    /// Restrict indexes to a region
+    pub fn index_market(mut self, market: IndexMarket) -> Self {
+        self.index_market = Some(market);
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+}
+
+impl EngineQuery for GoogleFinanceMarketsQuery {
+    type Response = GoogleFinanceMarketsResults;
+
+    fn engine(&self) -> &str {
+        "google_finance_markets"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        if self.index_market.is_some() && self.trend != MarketTrend::Indexes {
+            return Err(SerpError::InvalidParameter(
+                "index_market is only available for the indexes trend".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Results of a `google_finance` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleFinanceResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub summary: Option<QuoteSummary>,
+    pub graph: Option<Vec<GraphPoint>>,
+    pub knowledge_graph: Option<FinanceKnowledgeGraph>,
+    pub news_results: Option<Vec<FinanceNews>>,
+    pub financials: Option<Vec<FinancialStatement>>,
+    pub markets: Option<Markets>,
+    pub discover_more: Option<Vec<MarketGroup>>,
+}
+
+/// Current quote of a security
+#[derive(Debug, Deserialize, Clone)]
+pub struct QuoteSummary {
+    pub title: String,
+    pub stock: Option<String>,
+    pub exchange: Option<String>,
+    pub price: Option<String>,
+    pub extracted_price: Option<f64>,
+    /// Currency symbol, e.g. "$"
+    pub currency: Option<String>,
+    pub price_movement: Option<PriceMovement>,
+    pub market: Option<MarketSession>,
+    pub extensions: Option<Vec<String>>,
+}
+
+/// Pre-market or after-hours quote
+#[derive(Debug, Deserialize, Clone)]
+pub struct MarketSession {
+    /// e.g. "After hours"
+    pub trading: Option<String>,
+    pub price: Option<String>,
+    pub extracted_price: Option<f64>,
+    pub price_movement: Option<PriceMovement>,
+}
+
+/// Point of the price graph
+#[derive(Debug, Deserialize, Clone)]
+pub struct GraphPoint {
+    pub price: f64,
+    pub currency: Option<String>,
+    /// e.g. "Dec 13 2024, 09:30 AM UTC-05:00"
+    pub date: String,
+    pub volume: Option<u64>,
+}
+
+/// Key stats and company profile
+#[derive(Debug, Deserialize, Clone)]
+pub struct FinanceKnowledgeGraph {
+    pub key_stats: Option<KeyStats>,
+    pub about: Option<Vec<FinanceAbout>>,
+}
+
+/// Key statistics of a security
+#[derive(Debug, Deserialize, Clone)]
+pub struct KeyStats {
+    pub tags: Option<Vec<FinanceTag>>,
+    pub stats: Option<Vec<KeyStat>>,
+}
+
+impl KeyStats {
+    /// Numeric value of a stat by label (e.g. "Market cap", "P/E ratio")
+    pub fn value_of(&self, label: &str) -> Option<f64> {
+        self.stats
+            .as_ref()?
+            .iter()
+            .find(|stat| stat.label.eq_ignore_ascii_case(label))
+            .and_then(KeyStat::numeric_value)
+    }
+}
+
+/// Tag such as "Stock" or "US listed security"
+#[derive(Debug, Deserialize, Clone)]
+pub struct FinanceTag {
+    pub text: String,
+    pub description: Option<String>,
+    pub link: Option<String>,
+}
+
+/// Labelled statistic, e.g. "Market cap" = "2.78T USD"
+#[derive(Debug, Deserialize, Clone)]
+pub struct KeyStat {
+    pub label: String,
+    pub description: Option<String>,
+    pub value: String,
+}
+
+impl KeyStat {
+    /// Value as a number, with K/M/B/T suffixes expanded
+    ///
+    /// Ranges such as "$150.00 - $200.00" yield their first number.
+    pub fn numeric_value(&self) -> Option<f64> {
+        parse::parse_decimal(&self.value)
+    }
+}
+
+/// Company profile section
+#[derive(Debug, Deserialize, Clone)]
+pub struct FinanceAbout {
+    pub title: Option<String>,
+    pub description: Option<FinanceDescription>,
+    pub info: Option<Vec<KeyStat>>,
+}
+
+/// Company description
+#[derive(Debug, Deserialize, Clone)]
+pub struct FinanceDescription {
+    pub snippet: Option<String>,
+    pub link: Option<String>,
+    pub link_text: Option<String>,
+}
+
+/// News article, or a titled group of articles
+#[derive(Debug, Deserialize, Clone)]
+pub struct FinanceNews {
+    pub title: Option<String>,
+    pub snippet: Option<String>,
+    pub link: Option<String>,
+    pub source: Option<String>,
+    pub date: Option<String>,
+    pub thumbnail: Option<String>,
+    pub items: Option<Vec<FinanceNews>>,
+}
+
+/// Financial statement (income statement, balance sheet or cash flow)
+#[derive(Debug, Deserialize, Clone)]
+pub struct FinancialStatement {
+    pub title: String,
+    pub results: Vec<FinancialPeriod>,
+}
+
+/// Statement figures for one period
+#[derive(Debug, Deserialize, Clone)]
+pub struct FinancialPeriod {
+    /// e.g. "Sep 2024"
+    pub date: String,
+    /// "Quarterly" or "Annual"
+    pub period_type: Option<String>,
+    pub table: Vec<FinancialRow>,
+}
+
+impl FinancialPeriod {
+    /// Numeric value of a row by title (e.g. "Revenue", "Net income")
+    pub fn value_of(&self, title: &str) -> Option<f64> {
+        self.table
+            .iter()
+            .find(|row| row.title.eq_ignore_ascii_case(title))
+            .and_then(FinancialRow::numeric_value)
+    }
+}
+
+/// Line of a financial statement, e.g. "Revenue" = "94.93B", change "6.07%"
+#[derive(Debug, Deserialize, Clone)]
+pub struct FinancialRow {
+    pub title: String,
+    pub description: Option<String>,
+    pub value: String,
+    pub change: Option<String>,
+}
+
+impl FinancialRow {
+    /// Value as a number, with K/M/B/T suffixes expanded
+    pub fn numeric_value(&self) -> Option<f64> {
+        parse::parse_decimal(&self.value)
+    }
+
+    /// Year-over-year change in percent
+    pub fn change_percent(&self) -> Option<f64> {
+        self.change.as_deref().and_then(parse::parse_decimal)
+    }
+}
+
+/// Market overview by region and asset class
+#[derive(Debug, Deserialize, Clone)]
+pub struct Markets {
+    pub us: Option<Vec<MarketItem>>,
+    pub europe: Option<Vec<MarketItem>>,
+    pub asia: Option<Vec<MarketItem>>,
+    pub currencies: Option<Vec<MarketItem>>,
+    pub crypto: Option<Vec<MarketItem>>,
+    pub futures: Option<Vec<MarketItem>>,
+}
+
+/// Titled list of securities
+#[derive(Debug, Deserialize, Clone)]
+pub struct MarketGroup {
+    pub title: String,
+    pub subtitle: Option<String>,
+    #[serde(alias = "items")]
+    pub results: Vec<MarketItem>,
+}
+
+/// Security in a market overview
+#[derive(Debug, Deserialize, Clone)]
+pub struct MarketItem {
+    pub stock: Option<String>,
+    pub name: Option<String>,
+    pub link: Option<String>,
+    pub serpapi_link: Option<String>,
+    pub price: Option<String>,
+    pub extracted_price: Option<f64>,
+    pub currency: Option<String>,
+    pub price_movement: Option<PriceMovement>,
+}
+
+/// Results of a `google_finance_markets` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleFinanceMarketsResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub markets: Option<Markets>,
+    pub market_trends: Option<Vec<MarketGroup>>,
+    pub news_results: Option<Vec<FinanceNews>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finance_query_params() {
+        let query = GoogleFinanceQuery::ticker("AAPL", "NASDAQ").window(FinanceWindow::YearToDate);
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("q".to_string(), "AAPL:NASDAQ".to_string()),
+                ("window".to_string(), "YTD".to_string()),
+            ]
+        );
+
+        let markets = GoogleFinanceMarketsQuery::new(MarketTrend::MostActive);
+        assert_eq!(
+            markets.params().unwrap(),
+            vec![("trend".to_string(), "most-active".to_string())]
+        );
+        assert!(markets
+            .index_market(IndexMarket::AsiaPacific)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_finance_results_parsing() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "summary": {
+                "title": "Apple Inc",
+                "stock": "AAPL",
+                "exchange": "NASDAQ",
+                "price": "$248.13",
+                "extracted_price": 248.13,
+                "currency": "$",
+                "price_movement": {"percentage": 0.53, "value": 1.32, "movement": "Down"}
+            },
+            "graph": [{"price": 247.5, "currency": "USD", "date": "Dec 13 2024, 09:30 AM UTC-05:00", "volume": 51234}],
+            "knowledge_graph": {"key_stats": {"stats": [
+                {"label": "Market cap", "value": "3.75T USD"},
+                {"label": "P/E ratio", "value": "40.81"}
+            ]}},
+            "financials": [{"title": "Income Statement", "results": [{
+                "date": "Sep 2024",
+                "period_type": "Quarterly",
+                "table": [{"title": "Revenue", "value": "94.93B", "change": "6.07%"}]
+            }]}]
+        }"#;
+        let results: GoogleFinanceResults = serde_json::from_str(json).unwrap();
+        let movement = results.summary.unwrap().price_movement.unwrap();
+        assert_eq!(movement.signed_value(), Some(-1.32));
+
+        let stats = results.knowledge_graph.unwrap().key_stats.unwrap();
+        assert_eq!(stats.value_of("market cap"), Some(3.75e12));
+        assert_eq!(stats.value_of("P/E ratio"), Some(40.81));
+
+        let period = &results.financials.unwrap()[0].results[0];
+        assert_eq!(period.value_of("Revenue"), Some(94.93e9));
+        assert_eq!(period.table[0].change_percent(), Some(6.07));
+        assert_eq!(results.graph.unwrap()[0].volume, Some(51234));
+    }
+}
@@ -0,0 +1,702 @@
+#![allow(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    engines::{echoed_param, EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    parse,
+    response::{Price, SearchMetadata},
+};
+
+/// Maximum number of passengers Google Flights books at once
+const MAX_PASSENGERS: u8 = 9;
+
+/// Cabin class (travel_class parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TravelClass {
+    Economy,
+    PremiumEconomy,
+    Business,
+    First,
+}
+
+/// Maximum number of stops (stops parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopsFilter {
+    Any,
+    Nonstop,
+    OneStopOrFewer,
+    TwoStopsOrFewer,
+}
+
+/// Sort order for flights (sort_by parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlightSort {
+    TopFlights,
+    Price,
+    DepartureTime,
+    ArrivalTime,
+    Duration,
+    Emissions,
+}
+
+/// Travellers on a booking
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Passengers {
+    pub adults: u8,
+    pub children: u8,
+    pub infants_in_seat: u8,
+    pub infants_on_lap: u8,
+}
+
+impl Default for Passengers {
+    fn default() -> Self {
+        Self::adults(1)
+    }
+}
+
+impl Passengers {
+    /// Only adults
+    pub fn adults(adults: u8) -> Self {
+        Self {
+            adults,
+            children: 0,
+            infants_in_seat: 0,
+            infants_on_lap: 0,
+        }
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        let total = self.adults as u16
+            + self.children as u16
+            + self.infants_in_seat as u16
+            + self.infants_on_lap as u16;
+        if self.adults == 0 || total > MAX_PASSENGERS as u16 {
+            return Err(SerpError::InvalidParameter(format!(
+                "bookings need at least one adult and at most {} passengers",
+                MAX_PASSENGERS
+            )));
+        }
+        if self.infants_on_lap > self.adults {
+            return Err(SerpError::InvalidParameter(
+                "each infant on lap needs an adult".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// One leg of a multi-city trip
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FlightLeg {
+    pub departure_id: String,
+    pub arrival_id: String,
+    /// Departure date, "YYYY-MM-DD"
+    pub date: String,
+}
+
+impl FlightLeg {
+    /// Create a leg between airport codes (or kgmids) on a date
+    pub fn new(
+        departure_id: impl Into<String>,
+        arrival_id: impl Into<String>,
+        date: impl Into<String>,
+    ) -> Self {
+        Self {
+            departure_id: departure_id.into(),
+            arrival_id: arrival_id.into(),
+            date: date.into(),
+        }
+    }
+}
+
+/// Query for the `google_flights` engine.
+///
+/// Round trips return outbound options first; the return leg of an option is
+/// fetched with [`return_flights`](Self::return_flights), and the booking
+/// options of a complete itinerary with [`booking`](Self::booking).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::SerpClient;
+/// use serp_sdk::engines::google_flights::{GoogleFlightsQuery, StopsFilter, TravelClass};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = GoogleFlightsQuery::round_trip("CDG", "AUS", "2025-03-01", "2025-03-08")
+///     .travel_class(TravelClass::Business)
+///     .stops(StopsFilter::Nonstop)
+///     .currency("EUR");
+///
+/// let outbound = client.search_engine(query.clone()).await?;
+/// if let Some(best) = outbound.best_flights.as_ref().and_then(|f| f.first()) {
+///     println!("{:?}", outbound.price_of(best));
+///     if let Some(return_query) = query.return_flights(best) {
+///         let inbound = client.search_engine(return_query).await?;
+///         println!("{} return options", inbound.other_flights.map_or(0, |f| f.len()));
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleFlightsQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    departure_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    arrival_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    outbound_date: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    return_date: Option<String>,
+
+    #[serde(rename = "type")]
+    trip_type: u8,
+
+    #[serde(skip)]
+    legs: Vec<FlightLeg>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    travel_class: Option<u8>,
+
+    #[serde(skip)]
+    passengers: Option<Passengers>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stops: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort_by: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_price: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_airlines: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exclude_airlines: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    currency: Option<String>,
+
+    #[serde(rename = "gl", skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    departure_token: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    booking_token: Option<String>,
+}
+
+impl GoogleFlightsQuery {
+    fn trip(trip_type: u8) -> Self {
+        Self {
+            departure_id: None,
+            arrival_id: None,
+            outbound_date: None,
+            return_date: None,
+            trip_type,
+            legs: Vec::new(),
+            travel_class: None,
+            passengers: None,
+            stops: None,
+            sort_by: None,
+            max_price: None,
+            include_airlines: None,
+            exclude_airlines: None,
+            currency: None,
+            country: None,
+            language: None,
+            departure_token: None,
+            booking_token: None,
+        }
+    }
+
+    /// Search round-trip flights; airports may be comma-separated (e.g. "JFK,EWR")
+    pub fn round_trip(
+        departure_id: impl Into<String>,
+        arrival_id: impl Into<String>,
+        outbound_date: impl Into<String>,
+        return_date: impl Into<String>,
+    ) -> Self {
+        Self {
+            departure_id: Some(departure_id.into()),
+            arrival_id: Some(arrival_id.into()),
+            outbound_date: Some(outbound_date.into()),
+            return_date: Some(return_date.into()),
+            ..Self::trip(1)
+        }
+    }
+
+    /// Search one-way flights
+    pub fn one_way(
+        departure_id: impl Into<String>,
+        arrival_id: impl Into<String>,
+        outbound_date: impl Into<String>,
+    ) -> Self {
+        Self {
+            departure_id: Some(departure_id.into()),
+            arrival_id: Some(arrival_id.into()),
+            outbound_date: Some(outbound_date.into()),
+            ..Self::trip(2)
+        }
+    }
+
+    /// Search multi-city trips
+    pub fn multi_city(legs: Vec<FlightLeg>) -> Self {
+        Self {
+            legs,
+            ..Self::trip(3)
+        }
+    }
+
+    /// Set the cabin class
+    pub fn travel_class(mut self, class: TravelClass) -> Self {
+        self.travel_class = Some(match class {
+            TravelClass::Economy => 1,
+            TravelClass::PremiumEconomy => 2,
+            TravelClass::Business => 3,
+            TravelClass::First => 4,
+        });
+        self
+    }
+
+    /// Set the travellers (one adult by default)
+    pub fn passengers(mut self, passengers: Passengers) -> Self {
+        self.passengers = Some(passengers);
+        self
+    }
+
+    /// Limit the number of stops
+    pub fn stops(mut self, stops: StopsFilter) -> Self {
+        self.stops = Some(match stops {
+            StopsFilter::Any => 0,
+            StopsFilter::Nonstop => 1,
+            StopsFilter::OneStopOrFewer => 2,
+            StopsFilter::TwoStopsOrFewer => 3,
+        });
+        self
+    }
+
+    /// Set the sort order
+    pub fn sort_by(mut self, sort: FlightSort) -> Self {
+        self.sort_by = Some(match sort {
+            FlightSort::TopFlights => 1,
+            FlightSort::Price => 2,
+            FlightSort::DepartureTime => 3,
+            FlightSort::ArrivalTime => 4,
+            FlightSort::Duration => 5,
+            FlightSort::Emissions => 6,
+        });
+        self
+    }
+
+    /// Set the maximum ticket price, in the query currency
+    pub fn max_price(mut self, max_price: u32) -> Self {
+        self.max_price = Some(max_price);
+        self
+    }
+
+    /// Only include airlines or alliances (e.g. "UA,STAR_ALLIANCE")
+    pub fn include_airlines(mut self, airlines: impl Into<String>) -> Self {
+        self.include_airlines = Some(airlines.into());
+        self
+    }
+
+    /// Exclude airlines or alliances (e.g. "NK,F9")
+    pub fn exclude_airlines(mut self, airlines: impl Into<String>) -> Self {
+        self.exclude_airlines = Some(airlines.into());
+        self
+    }
+
+    /// Set the currency of returned prices (e.g. "EUR"; USD by default)
+    pub fn currency(mut self, currency: impl Into<String>) -> Self {
+        self.currency = Some(currency.into());
+        self
+    }
+
+    /// Set the country (gl parameter)
+    pub fn country(mut self, gl: impl Into<String>) -> Self {
+        self.country = Some(gl.into());
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Build the query for the next leg of a selected flight option
+    pub fn return_flights(&self, option: &FlightOption) -> Option<Self> {
+        let token = option.departure_token.clone()?;
+        Some(Self {
+            departure_token: Some(token),
+            booking_token: None,
+            ..self.clone()
+        })
+    }
+
+    /// Build the query for the booking options of a complete itinerary
+    pub fn booking(&self, option: &FlightOption) -> Option<Self> {
+        let token = option.booking_token.clone()?;
+        Some(Self {
+            departure_token: None,
+            booking_token: Some(token),
+            ..self.clone()
+        })
+    }
+}
+
+impl EngineQuery for GoogleFlightsQuery {
+    type Response = GoogleFlightsResults;
+
+    fn engine(&self) -> &str {
+        "google_flights"
+    }
+
+    fn params(&self) -> SerpResult<Vec<(String, String)>> {
+        let encoded = serde_urlencoded::to_string(self)?;
+        let mut params: Vec<(String, String)> = url::form_urlencoded::parse(encoded.as_bytes())
+            .into_owned()
+            .collect();
+        if !self.legs.is_empty() {
+            params.push((
+                "multi_city_json".to_string(),
+                serde_json::to_string(&self.legs)?,
+            ));
+        }
+        if let Some(passengers) = self.passengers {
+            let counts = [
+                ("adults", passengers.adults),
+                ("children", passengers.children),
+                ("infants_in_seat", passengers.infants_in_seat),
+                ("infants_on_lap", passengers.infants_on_lap),
+            ];
+            params.extend(
+                counts
+                    .iter()
+                    .filter(|(key, count)| *count > 0 || *key == "adults")
+                    .map(|(key, count)| (key.to_string(), count.to_string())),
+            );
+        }
+        Ok(params)
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        if let Some(passengers) = &self.passengers {
+            passengers.validate()?;
+        }
+        let dates = self
+            .outbound_date
+            .iter()
+            .chain(&self.return_date)
+            .chain(self.legs.iter().map(|leg| &leg.date));
+        for date in dates {
+            if !parse::is_iso_date(date) {
+                return Err(SerpError::InvalidParameter(format!(
+                    "flight dates must be formatted as YYYY-MM-DD, got {}",
+                    date
+                )));
+            }
+        }
+        if let (Some(outbound), Some(inbound)) = (&self.outbound_date, &self.return_date) {
+            if inbound < outbound {
+                return Err(SerpError::InvalidParameter(
+                    "return_date must not be before outbound_date".to_string(),
+                ));
+            }
+        }
+        if self.trip_type == 3 && self.legs.len() < 2 {
+            return Err(SerpError::InvalidParameter(
+                "multi-city trips need at least two legs".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Results of a `google_flights` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleFlightsResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub best_flights: Option<Vec<FlightOption>>,
+    pub other_flights: Option<Vec<FlightOption>>,
+    pub price_insights: Option<PriceInsights>,
+    pub airports: Option<Vec<AirportInfo>>,
+    pub booking_options: Option<Vec<BookingOption>>,
+}
+
+impl GoogleFlightsResults {
+    /// Currency of the prices in this response
+    pub fn currency(&self) -> &str {
+        echoed_param(&self.search_parameters, "currency").unwrap_or("USD")
+    }
+
+    /// Price of a flight option in the response currency
+    pub fn price_of(&self, option: &FlightOption) -> Option<Price> {
+        option.price.map(|amount| Price {
+            amount,
+            currency: Some(self.currency().to_string()),
+        })
+    }
+}
+
+/// Itinerary option, made of one or more flights
+#[derive(Debug, Deserialize, Clone)]
+pub struct FlightOption {
+    pub flights: Vec<FlightSegment>,
+    pub layovers: Option<Vec<Layover>>,
+    /// Total duration in minutes
+    pub total_duration: Option<u32>,
+    pub carbon_emissions: Option<CarbonEmissions>,
+    pub price: Option<f64>,
+    #[serde(rename = "type")]
+    pub trip_type: Option<String>,
+    pub airline_logo: Option<String>,
+    pub extensions: Option<Vec<String>>,
+    /// Token for the next leg's options
+    pub departure_token: Option<String>,
+    /// Token for the booking options of the itinerary
+    pub booking_token: Option<String>,
+}
+
+/// Single flight within an itinerary
+#[derive(Debug, Deserialize, Clone)]
+pub struct FlightSegment {
+    pub departure_airport: FlightAirport,
+    pub arrival_airport: FlightAirport,
+    /// Duration in minutes
+    pub duration: Option<u32>,
+    pub airplane: Option<String>,
+    pub airline: Option<String>,
+    pub airline_logo: Option<String>,
+    pub travel_class: Option<String>,
+    pub flight_number: Option<String>,
+    pub legroom: Option<String>,
+    pub extensions: Option<Vec<String>>,
+    pub overnight: Option<bool>,
+    pub often_delayed_by_over_30_min: Option<bool>,
+}
+
+/// Airport and local time of a departure or arrival
+#[derive(Debug, Deserialize, Clone)]
+pub struct FlightAirport {
+    pub name: Option<String>,
+    pub id: String,
+    /// Local time, "YYYY-MM-DD HH:MM"
+    pub time: Option<String>,
+}
+
+/// Connection between two flights
+#[derive(Debug, Deserialize, Clone)]
+pub struct Layover {
+    /// Duration in minutes
+    pub duration: Option<u32>,
+    pub name: Option<String>,
+    pub id: Option<String>,
+    pub overnight: Option<bool>,
+}
+
+/// Emissions of an itinerary, in grams
+#[derive(Debug, Deserialize, Clone)]
+pub struct CarbonEmissions {
+    pub this_flight: Option<u64>,
+    pub typical_for_this_route: Option<u64>,
+    pub difference_percent: Option<i32>,
+}
+
+/// How current prices compare to the usual prices of the route
+#[derive(Debug, Deserialize, Clone)]
+pub struct PriceInsights {
+    pub lowest_price: Option<f64>,
+    /// "low", "typical" or "high"
+    pub price_level: Option<String>,
+    /// Low and high end of the typical price
+    pub typical_price_range: Option<Vec<f64>>,
+    /// Pairs of Unix timestamp and lowest price
+    pub price_history: Option<Vec<(u64, f64)>>,
+}
+
+/// Airports matching a departure or arrival
+#[derive(Debug, Deserialize, Clone)]
+pub struct AirportInfo {
+    pub departure: Option<Vec<AirportDetails>>,
+    pub arrival: Option<Vec<AirportDetails>>,
+}
+
+/// Airport with the city it serves
+#[derive(Debug, Deserialize, Clone)]
+pub struct AirportDetails {
+    pub airport: FlightAirport,
+    pub city: Option<String>,
+    pub country: Option<String>,
+    pub country_code: Option<String>,
+    pub image: Option<String>,
+    pub thumbnail: Option<String>,
+}
+
+/// Way to book an itinerary, either together or as separate tickets
+#[derive(Debug, Deserialize, Clone)]
+pub struct BookingOption {
+    pub separate_tickets: Option<bool>,
+    pub together: Option<BookingOffer>,
+    pub departing: Option<BookingOffer>,
+    pub returning: Option<BookingOffer>,
+}
+
+/// Offer of a booking provider
+#[derive(Debug, Deserialize, Clone)]
+pub struct BookingOffer {
+    pub book_with: Option<String>,
+    pub airline_logos: Option<Vec<String>>,
+    pub marketed_as: Option<Vec<String>>,
+    pub price: Option<f64>,
+    pub local_prices: Option<Vec<LocalPrice>>,
+    pub option_title: Option<String>,
+    pub extensions: Option<Vec<String>>,
+    pub baggage_prices: Option<Vec<String>>,
+    pub booking_request: Option<BookingRequest>,
+}
+
+/// Price in the provider's local currency
+#[derive(Debug, Deserialize, Clone)]
+pub struct LocalPrice {
+    pub currency: String,
+    pub price: f64,
+}
+
+impl From<&LocalPrice> for Price {
+    fn from(local: &LocalPrice) -> Self {
+        Price {
+            amount: local.price,
+            currency: Some(local.currency.clone()),
+        }
+    }
+}
+
+/// Request that opens the provider's booking page
+#[derive(Debug, Deserialize, Clone)]
+pub struct BookingRequest {
+    pub url: String,
+    pub post_data: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flights_query_params() {
+        let query = GoogleFlightsQuery::one_way("CDG", "AUS", "2025-03-01")
+            .travel_class(TravelClass::Business)
+            .passengers(Passengers {
+                adults: 2,
+                children: 1,
+                infants_in_seat: 0,
+                infants_on_lap: 0,
+            });
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("departure_id".to_string(), "CDG".to_string()),
+                ("arrival_id".to_string(), "AUS".to_string()),
+                ("outbound_date".to_string(), "2025-03-01".to_string()),
+                ("type".to_string(), "2".to_string()),
+                ("travel_class".to_string(), "3".to_string()),
+                ("adults".to_string(), "2".to_string()),
+                ("children".to_string(), "1".to_string()),
+            ]
+        );
+
+        let multi = GoogleFlightsQuery::multi_city(vec![
+            FlightLeg::new("CDG", "AUS", "2025-03-01"),
+            FlightLeg::new("AUS", "SFO", "2025-03-05"),
+        ]);
+        let params = multi.params().unwrap();
+        assert!(params.contains(&(
+            "multi_city_json".to_string(),
+            r#"[{"departure_id":"CDG","arrival_id":"AUS","date":"2025-03-01"},{"departure_id":"AUS","arrival_id":"SFO","date":"2025-03-05"}]"#
+                .to_string()
+        )));
+    }
+
+    #[test]
+    fn test_flights_query_validation() {
+        assert!(
+            GoogleFlightsQuery::round_trip("CDG", "AUS", "2025-03-08", "2025-03-01")
+                .validate()
+                .is_err()
+        );
+        assert!(GoogleFlightsQuery::one_way("CDG", "AUS", "03/01/2025")
+            .validate()
+            .is_err());
+        assert!(GoogleFlightsQuery::one_way("CDG", "AUS", "2025-03-01")
+            .passengers(Passengers {
+                adults: 1,
+                children: 0,
+                infants_in_seat: 0,
+                infants_on_lap: 2,
+            })
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_flights_results_parsing() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "search_parameters": {"engine": "google_flights", "currency": "EUR"},
+            "best_flights": [{
+                "flights": [{
+                    "departure_airport": {"name": "Paris CDG", "id": "CDG", "time": "2025-03-01 10:30"},
+                    "arrival_airport": {"name": "Austin", "id": "AUS", "time": "2025-03-01 15:00"},
+                    "duration": 690,
+                    "airline": "Air France",
+                    "flight_number": "AF 644"
+                }],
+                "total_duration": 690,
+                "carbon_emissions": {"this_flight": 580000, "difference_percent": -4},
+                "price": 842,
+                "departure_token": "DEP"
+            }],
+            "price_insights": {
+                "lowest_price": 842,
+                "price_level": "typical",
+                "typical_price_range": [750, 1100],
+                "price_history": [[1700000000, 900], [1700086400, 870]]
+            }
+        }"#;
+        let results: GoogleFlightsResults = serde_json::from_str(json).unwrap();
+        let best = &results.best_flights.as_ref().unwrap()[0];
+        assert_eq!(
+            results.price_of(best),
+            Some(Price {
+                amount: 842.0,
+                currency: Some("EUR".to_string())
+            })
+        );
+        let insights = results.price_insights.as_ref().unwrap();
+        assert_eq!(
+            insights.price_history.as_ref().unwrap()[1],
+            (1_700_086_400, 870.0)
+        );
+
+        let query = GoogleFlightsQuery::round_trip("CDG", "AUS", "2025-03-01", "2025-03-08");
+        let next = query.return_flights(best).unwrap();
+        assert_eq!(next.departure_token.as_deref(), Some("DEP"));
+        assert!(query.booking(best).is_none());
+    }
+}
@@ -0,0 +1,559 @@
+#![allow(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    engines::{check_price_range, echoed_param, EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    parse,
+    response::{GpsCoordinates, Price, SearchMetadata, SerpapiPagination},
+};
+
+/// Sort order for hotels (sort_by parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HotelSort {
+    LowestPrice,
+    HighestRating,
+    MostReviewed,
+}
+
+/// Minimum guest rating (rating parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HotelRating {
+    ThreeFivePlus,
+    FourPlus,
+    FourFivePlus,
+}
+
+/// Stay dates and guests shared by hotel searches and property details
+#[derive(Debug, Clone, Serialize)]
+struct Stay {
+    check_in_date: String,
+    check_out_date: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    adults: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    children: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    children_ages: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    currency: Option<String>,
+
+    #[serde(rename = "gl", skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+}
+
+impl Stay {
+    fn validate(&self) -> SerpResult<()> {
+        for date in [&self.check_in_date, &self.check_out_date] {
+            if !parse::is_iso_date(date) {
+                return Err(SerpError::InvalidParameter(format!(
+                    "stay dates must be formatted as YYYY-MM-DD, got {}",
+                    date
+                )));
+            }
+        }
+        if self.check_out_date <= self.check_in_date {
+            return Err(SerpError::InvalidParameter(
+                "check_out_date must be after check_in_date".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Query for the `google_hotels` engine.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::SerpClient;
+/// use serp_sdk::engines::google_hotels::{GoogleHotelsQuery, HotelSort};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = GoogleHotelsQuery::new("Bali Resorts", "2025-06-01", "2025-06-05")
+///     .guests(2, &[7])?
+///     .price_range(Some(100), Some(400))?
+///     .sort_by(HotelSort::HighestRating)
+///     .currency("EUR");
+///
+/// let results = client.search_engine(query.clone()).await?;
+/// for property in results.properties.as_deref().unwrap_or_default() {
+///     let nightly = property.nightly_price(results.currency());
+///     println!("{}: {:?}", property.name, nightly);
+///
+///     if let Some(details_query) = query.details(property) {
+///         let details = client.search_engine(details_query).await?;
+///         println!("{:?}", details.property.address);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleHotelsQuery {
+    q: String,
+
+    #[serde(flatten)]
+    stay: Stay,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort_by: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_price: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_price: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rating: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hotel_class: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    amenities: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    property_types: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    free_cancellation: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vacation_rentals: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_page_token: Option<String>,
+}
+
+fn join<T: ToString>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+impl GoogleHotelsQuery {
+    /// Search hotels for a destination and stay dates ("YYYY-MM-DD")
+    pub fn new(
+        query: impl Into<String>,
+        check_in_date: impl Into<String>,
+        check_out_date: impl Into<String>,
+    ) -> Self {
+        Self {
+            q: query.into(),
+            stay: Stay {
+                check_in_date: check_in_date.into(),
+                check_out_date: check_out_date.into(),
+                adults: None,
+                children: None,
+                children_ages: None,
+                currency: None,
+                country: None,
+                language: None,
+            },
+            sort_by: None,
+            min_price: None,
+            max_price: None,
+            rating: None,
+            hotel_class: None,
+            amenities: None,
+            property_types: None,
+            free_cancellation: None,
+            vacation_rentals: None,
+            next_page_token: None,
+        }
+    }
+
+    /// Set the number of adults and the age of each child (1-17)
+    pub fn guests(mut self, adults: u8, children_ages: &[u8]) -> SerpResult<Self> {
+        if adults == 0 {
+            return Err(SerpError::InvalidParameter(
+                "at least one adult is required".to_string(),
+            ));
+        }
+        if children_ages.iter().any(|age| !(1..=17).contains(age)) {
+            return Err(SerpError::InvalidParameter(
+                "children ages must be between 1 and 17".to_string(),
+            ));
+        }
+        self.stay.adults = Some(adults);
+        self.stay.children = (!children_ages.is_empty()).then_some(children_ages.len() as u8);
+        self.stay.children_ages = (!children_ages.is_empty()).then(|| join(children_ages));
+        Ok(self)
+    }
+
+    /// Set the currency of returned prices (e.g. "EUR"; USD by default)
+    pub fn currency(mut self, currency: impl Into<String>) -> Self {
+        self.stay.currency = Some(currency.into());
+        self
+    }
+
+    /// Set the country (gl parameter)
+    pub fn country(mut self, gl: impl Into<String>) -> Self {
+        self.stay.country = Some(gl.into());
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.stay.language = Some(hl.into());
+        self
+    }
+
+    /// Set the sort order
+    pub fn sort_by(mut self, sort: HotelSort) -> Self {
+        self.sort_by = Some(match sort {
+            HotelSort::LowestPrice => 3,
+            HotelSort::HighestRating => 8,
+            HotelSort::MostReviewed => 13,
+        });
+        self
+    }
+
+    /// Only include properties whose nightly price is within a range
+    pub fn price_range(mut self, min: Option<u32>, max: Option<u32>) -> SerpResult<Self> {
+        check_price_range(min.map(f64::from), max.map(f64::from))?;
+        self.min_price = min;
+        self.max_price = max;
+        Ok(self)
+    }
+
+    /// Set the minimum guest rating
+    pub fn rating(mut self, rating: HotelRating) -> Self {
+        self.rating = Some(match rating {
+            HotelRating::ThreeFivePlus => 7,
+            HotelRating::FourPlus => 8,
+            HotelRating::FourFivePlus => 9,
+        });
+        self
+    }
+
+    /// Only include hotels of the given star classes (2-5)
+    pub fn hotel_class(mut self, classes: &[u8]) -> SerpResult<Self> {
+        if classes.iter().any(|class| !(2..=5).contains(class)) {
+            return Err(SerpError::InvalidParameter(
+                "hotel class must be between 2 and 5".to_string(),
+            ));
+        }
+        self.hotel_class = Some(join(classes));
+        Ok(self)
+    }
+
+    /// Only include properties with all of the given amenity IDs
+    /// Examples: 35 (free Wi-Fi), 9 (free breakfast), 19 (pool)
+    pub fn amenities(mut self, amenities: &[u32]) -> Self {
+        self.amenities = Some(join(amenities));
+        self
+    }
+
+    /// Only include the given property type IDs
+    pub fn property_types(mut self, property_types: &[u32]) -> Self {
+        self.property_types = Some(join(property_types));
+        self
+    }
+
+    /// Only include offers with free cancellation
+    pub fn free_cancellation(mut self) -> Self {
+        self.free_cancellation = Some(true);
+        self
+    }
+
+    /// Search vacation rentals instead of hotels
+    pub fn vacation_rentals(mut self) -> Self {
+        self.vacation_rentals = Some(true);
+        self
+    }
+
+    /// Continue from a page token returned by a previous response
+    pub fn page_token(mut self, token: impl Into<String>) -> Self {
+        self.next_page_token = Some(token.into());
+        self
+    }
+
+    /// Build the query for the details of a property, keeping stay and guests
+    pub fn details(&self, property: &HotelProperty) -> Option<GoogleHotelDetailsQuery> {
+        Some(GoogleHotelDetailsQuery {
+            q: self.q.clone(),
+            stay: self.stay.clone(),
+            property_token: property.property_token.clone()?,
+        })
+    }
+}
+
+impl EngineQuery for GoogleHotelsQuery {
+    type Response = GoogleHotelsResults;
+
+    fn engine(&self) -> &str {
+        "google_hotels"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        self.stay.validate()
+    }
+
+    fn next_page(&self, response: &GoogleHotelsResults) -> Option<Self> {
+        response
+            .serpapi_pagination
+            .as_ref()
+            .and_then(|p| p.next_page_token.clone())
+            .map(|token| self.clone().page_token(token))
+    }
+}
+
+/// Query for the details of a property on the `google_hotels` engine.
+///
+/// Built from a search with [`GoogleHotelsQuery::details`].
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleHotelDetailsQuery {
+    q: String,
+
+    #[serde(flatten)]
+    stay: Stay,
+
+    property_token: String,
+}
+
+impl EngineQuery for GoogleHotelDetailsQuery {
+    type Response = GoogleHotelDetailsResults;
+
+    fn engine(&self) -> &str {
+        "google_hotels"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        self.stay.validate()
+    }
+}
+
+/// Results of a `google_hotels` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleHotelsResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub properties: Option<Vec<HotelProperty>>,
+    pub ads: Option<Vec<HotelAd>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+impl GoogleHotelsResults {
+    /// Currency of the prices in this response
+    pub fn currency(&self) -> &str {
+        echoed_param(&self.search_parameters, "currency").unwrap_or("USD")
+    }
+}
+
+/// Results of a property details search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleHotelDetailsResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    #[serde(flatten)]
+    pub property: HotelProperty,
+}
+
+impl GoogleHotelDetailsResults {
+    /// Currency of the prices in this response
+    pub fn currency(&self) -> &str {
+        echoed_param(&self.search_parameters, "currency").unwrap_or("USD")
+    }
+}
+
+/// Hotel or vacation rental
+#[derive(Debug, Deserialize, Clone)]
+pub struct HotelProperty {
+    #[serde(rename = "type")]
+    pub property_type: Option<String>,
+    pub name: String,
+    pub description: Option<String>,
+    pub link: Option<String>,
+    pub address: Option<String>,
+    pub phone: Option<String>,
+    pub property_token: Option<String>,
+    pub serpapi_property_details_link: Option<String>,
+    pub gps_coordinates: Option<GpsCoordinates>,
+    pub check_in_time: Option<String>,
+    pub check_out_time: Option<String>,
+    pub rate_per_night: Option<HotelRate>,
+    pub total_rate: Option<HotelRate>,
+    pub prices: Option<Vec<HotelPrice>>,
+    pub featured_prices: Option<Vec<HotelPrice>>,
+    pub hotel_class: Option<String>,
+    pub extracted_hotel_class: Option<u8>,
+    pub images: Option<Vec<HotelImage>>,
+    pub overall_rating: Option<f64>,
+    pub reviews: Option<u32>,
+    pub location_rating: Option<f64>,
+    pub amenities: Option<Vec<String>>,
+    pub excluded_amenities: Option<Vec<String>>,
+    pub essential_info: Option<Vec<String>>,
+    pub nearby_places: Option<Vec<NearbyPlace>>,
+}
+
+impl HotelProperty {
+    /// Lowest nightly price, in the response currency
+    pub fn nightly_price(&self, currency: &str) -> Option<Price> {
+        self.rate_per_night.as_ref()?.lowest_price(currency)
+    }
+
+    /// Lowest total price of the stay, in the response currency
+    pub fn total_price(&self, currency: &str) -> Option<Price> {
+        self.total_rate.as_ref()?.lowest_price(currency)
+    }
+}
+
+/// Nightly or total rate
+#[derive(Debug, Deserialize, Clone)]
+pub struct HotelRate {
+    pub lowest: Option<String>,
+    pub extracted_lowest: Option<f64>,
+    pub before_taxes_fees: Option<String>,
+    pub extracted_before_taxes_fees: Option<f64>,
+}
+
+impl HotelRate {
+    /// Lowest rate as amount and currency
+    pub fn lowest_price(&self, currency: &str) -> Option<Price> {
+        Price::from_parts(self.lowest.as_deref(), self.extracted_lowest).map(|price| Price {
+            currency: price.currency.or_else(|| Some(currency.to_string())),
+            ..price
+        })
+    }
+}
+
+/// Rate offered by a booking site
+#[derive(Debug, Deserialize, Clone)]
+pub struct HotelPrice {
+    pub source: String,
+    pub logo: Option<String>,
+    pub link: Option<String>,
+    pub num_guests: Option<u8>,
+    pub rate_per_night: Option<HotelRate>,
+    pub total_rate: Option<HotelRate>,
+}
+
+/// Property photo
+#[derive(Debug, Deserialize, Clone)]
+pub struct HotelImage {
+    pub thumbnail: Option<String>,
+    pub original_image: Option<String>,
+}
+
+/// Place near a property with ways to get there
+#[derive(Debug, Deserialize, Clone)]
+pub struct NearbyPlace {
+    pub name: String,
+    pub transportations: Option<Vec<Transportation>>,
+}
+
+/// Way to reach a nearby place
+#[derive(Debug, Deserialize, Clone)]
+pub struct Transportation {
+    #[serde(rename = "type")]
+    pub transportation_type: String,
+    pub duration: Option<String>,
+}
+
+/// Sponsored hotel offer
+#[derive(Debug, Deserialize, Clone)]
+pub struct HotelAd {
+    pub name: String,
+    pub source: Option<String>,
+    pub link: Option<String>,
+    pub price: Option<String>,
+    pub extracted_price: Option<f64>,
+    pub overall_rating: Option<f64>,
+    pub reviews: Option<u32>,
+    pub hotel_class: Option<u8>,
+    pub property_token: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hotels_query_params() {
+        let query = GoogleHotelsQuery::new("Bali", "2025-06-01", "2025-06-05")
+            .guests(2, &[5, 9])
+            .unwrap()
+            .hotel_class(&[4, 5])
+            .unwrap()
+            .amenities(&[35, 9])
+            .sort_by(HotelSort::LowestPrice);
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("q".to_string(), "Bali".to_string()),
+                ("check_in_date".to_string(), "2025-06-01".to_string()),
+                ("check_out_date".to_string(), "2025-06-05".to_string()),
+                ("adults".to_string(), "2".to_string()),
+                ("children".to_string(), "2".to_string()),
+                ("children_ages".to_string(), "5,9".to_string()),
+                ("sort_by".to_string(), "3".to_string()),
+                ("hotel_class".to_string(), "4,5".to_string()),
+                ("amenities".to_string(), "35,9".to_string()),
+            ]
+        );
+        assert!(GoogleHotelsQuery::new("Bali", "2025-06-05", "2025-06-01")
+            .validate()
+            .is_err());
+        assert!(query.clone().hotel_class(&[6]).is_err());
+    }
+
+    #[test]
+    fn test_hotels_results_parsing() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "search_parameters": {"engine": "google_hotels", "currency": "EUR"},
+            "properties": [{
+                "type": "hotel",
+                "name": "Ocean Resort",
+                "property_token": "PT1",
+                "rate_per_night": {"lowest": "€120", "extracted_lowest": 120},
+                "total_rate": {"lowest": "480", "extracted_lowest": 480},
+                "gps_coordinates": {"latitude": -8.7, "longitude": 115.2},
+                "overall_rating": 4.6,
+                "amenities": ["Free Wi-Fi", "Pool"]
+            }],
+            "serpapi_pagination": {"next_page_token": "NEXT"}
+        }"#;
+        let results: GoogleHotelsResults = serde_json::from_str(json).unwrap();
+        let property = &results.properties.as_ref().unwrap()[0];
+        let expected = |amount| {
+            Some(Price {
+                amount,
+                currency: Some("EUR".to_string()),
+            })
+        };
+        assert_eq!(property.nightly_price(results.currency()), expected(120.0));
+        assert_eq!(property.total_price(results.currency()), expected(480.0));
+
+        let query = GoogleHotelsQuery::new("Bali", "2025-06-01", "2025-06-05");
+        assert_eq!(
+            query
+                .next_page(&results)
+                .unwrap()
+                .next_page_token
+                .as_deref(),
+            Some("NEXT")
+        );
+        let details = query.details(property).unwrap();
+        assert!(details
+            .params()
+            .unwrap()
+            .contains(&("property_token".to_string(), "PT1".to_string())));
+    }
+}
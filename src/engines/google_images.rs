@@ -0,0 +1,404 @@
+#![allow(missing_docs)]
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+
+use crate::{
+    client::SerpClient,
+    engines::{has_next_page, EngineParameters, EngineQuery},
+    error::SerpResult,
+    response::{ImageResult, SearchMetadata, SerpapiPagination, SuggestedSearch},
+    streaming::StreamConfig,
+};
+
+/// Image size filter (imgsz parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ImageSize {
+    #[serde(rename = "l")]
+    Large,
+    #[serde(rename = "m")]
+    Medium,
+    #[serde(rename = "i")]
+    Icon,
+}
+
+/// Image aspect ratio filter (imgar parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ImageAspectRatio {
+    #[serde(rename = "s")]
+    Square,
+    #[serde(rename = "t")]
+    Tall,
+    #[serde(rename = "w")]
+    Wide,
+    #[serde(rename = "xw")]
+    Panoramic,
+}
+
+/// Image color filter (image_color parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageColor {
+    #[serde(rename = "bw")]
+    BlackAndWhite,
+    #[serde(rename = "trans")]
+    Transparent,
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Teal,
+    Blue,
+    Purple,
+    Pink,
+    White,
+    Gray,
+    Black,
+    Brown,
+}
+
+/// Image type filter (image_type parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageType {
+    Face,
+    Photo,
+    Clipart,
+    Lineart,
+    Animated,
+}
+
+/// Usage rights filter (licenses parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ImageLicense {
+    /// Free to use or share
+    #[serde(rename = "f")]
+    FreeToShare,
+    /// Free to use or share, even commercially
+    #[serde(rename = "fc")]
+    FreeToShareCommercially,
+    /// Free to use, share or modify
+    #[serde(rename = "fm")]
+    FreeToModify,
+    /// Free to use, share or modify, even commercially
+    #[serde(rename = "fmc")]
+    FreeToModifyCommercially,
+    /// Creative Commons licenses
+    #[serde(rename = "cl")]
+    CreativeCommons,
+    /// Commercial and other licenses
+    #[serde(rename = "ol")]
+    Commercial,
+}
+
+/// Query for the `google_images` engine.
+///
+/// Pages hold up to 100 images and are selected with the zero-based `ijn`
+/// parameter; use [`SerpClient::images_stream`] to iterate over every image.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::SerpClient;
+/// use serp_sdk::engines::google_images::{GoogleImagesQuery, ImageLicense, ImageSize};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = GoogleImagesQuery::new("ferris crab")
+///     .size(ImageSize::Large)
+///     .license(ImageLicense::CreativeCommons);
+///
+/// let results = client.search_engine(query).await?;
+/// for image in results.images_results.unwrap_or_default() {
+///     println!(
+///         "{} ({}x{})",
+///         image.original.unwrap_or_default(),
+///         image.original_width.unwrap_or(0),
+///         image.original_height.unwrap_or(0)
+///     );
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleImagesQuery {
+    q: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    google_domain: Option<String>,
+
+    #[serde(rename = "gl", skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chips: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    imgsz: Option<ImageSize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    imgar: Option<ImageAspectRatio>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image_color: Option<ImageColor>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image_type: Option<ImageType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    licenses: Option<ImageLicense>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    safe: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ijn: Option<u32>,
+}
+
+impl GoogleImagesQuery {
+    /// Create an image search query
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            q: query.into(),
+            location: None,
+            google_domain: None,
+            country: None,
+            language: None,
+            chips: None,
+            imgsz: None,
+            imgar: None,
+            image_color: None,
+            image_type: None,
+            licenses: None,
+            safe: None,
+            ijn: None,
+        }
+    }
+
+    /// Set the location to search from
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    /// Set the Google domain (e.g. "google.co.uk")
+    pub fn domain(mut self, google_domain: impl Into<String>) -> Self {
+        self.google_domain = Some(google_domain.into());
+        self
+    }
+
+    /// Set the country (gl parameter)
+    pub fn country(mut self, gl: impl Into<String>) -> Self {
+        self.country = Some(gl.into());
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Refine with a suggested search (`chips` of a [`SuggestedSearch`])
+    pub fn chips(mut self, chips: impl Into<String>) -> Self {
+        self.chips = Some(chips.into());
+        self
+    }
+
+    /// Filter by image size
+    pub fn size(mut self, size: ImageSize) -> Self {
+        self.imgsz = Some(size);
+        self
+    }
+
+    /// Filter by aspect ratio
+    pub fn aspect_ratio(mut self, ratio: ImageAspectRatio) -> Self {
+        self.imgar = Some(ratio);
+        self
+    }
+
+    /// Filter by dominant color
+    pub fn color(mut self, color: ImageColor) -> Self {
+        self.image_color = Some(color);
+        self
+    }
+
+    /// Filter by image type
+    pub fn image_type(mut self, image_type: ImageType) -> Self {
+        self.image_type = Some(image_type);
+        self
+    }
+
+    /// Filter by usage rights
+    pub fn license(mut self, license: ImageLicense) -> Self {
+        self.licenses = Some(license);
+        self
+    }
+
+    /// Set safe search level ("active" or "off")
+    pub fn safe_search(mut self, safe: impl Into<String>) -> Self {
+        self.safe = Some(safe.into());
+        self
+    }
+
+    /// Set the zero-based page number (ijn parameter)
+    pub fn page(mut self, ijn: u32) -> Self {
+        self.ijn = Some(ijn);
+        self
+    }
+}
+
+impl EngineQuery for GoogleImagesQuery {
+    type Response = GoogleImagesResults;
+
+    fn engine(&self) -> &str {
+        "google_images"
+    }
+
+    fn next_page(&self, response: &GoogleImagesResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        has_next.then(|| {
+            let ijn = self.ijn.unwrap_or(0) + 1;
+            self.clone().page(ijn)
+        })
+    }
+}
+
+/// Query for the `google_images_related_content` engine.
+///
+/// Returns the images Google shows next to an image when it is opened.
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleImagesRelatedContentQuery {
+    related_content_id: String,
+
+    #[serde(rename = "gl", skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+}
+
+impl GoogleImagesRelatedContentQuery {
+    /// Create a query for the `related_content_id` of an image result
+    pub fn new(related_content_id: impl Into<String>) -> Self {
+        Self {
+            related_content_id: related_content_id.into(),
+            country: None,
+            language: None,
+        }
+    }
+
+    /// Set the country (gl parameter)
+    pub fn country(mut self, gl: impl Into<String>) -> Self {
+        self.country = Some(gl.into());
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+}
+
+impl EngineQuery for GoogleImagesRelatedContentQuery {
+    type Response = GoogleImagesRelatedContentResults;
+
+    fn engine(&self) -> &str {
+        "google_images_related_content"
+    }
+}
+
+impl SerpClient {
+    /// Stream individual Google Images results across pages
+    ///
+    /// Pages are requested with an increasing `ijn` until SerpAPI stops returning
+    /// a next page or `config.max_pages` is reached.
+    pub fn images_stream(
+        &self,
+        query: GoogleImagesQuery,
+        config: StreamConfig,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<ImageResult>> + Send + '_>> {
+        self.engine_items_stream(query, config, |page| {
+            page.images_results.unwrap_or_default()
+        })
+    }
+}
+
+/// Results of a `google_images` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleImagesResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub images_results: Option<Vec<ImageResult>>,
+    pub suggested_searches: Option<Vec<SuggestedSearch>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Results of a `google_images_related_content` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleImagesRelatedContentResults {
+    pub search_metadata: SearchMetadata,
+    pub related_content: Option<Vec<ImageResult>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_images_query_params() {
+        let query = GoogleImagesQuery::new("ferris")
+            .size(ImageSize::Large)
+            .color(ImageColor::Transparent)
+            .image_type(ImageType::Clipart)
+            .license(ImageLicense::CreativeCommons)
+            .page(2);
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("q".to_string(), "ferris".to_string()),
+                ("imgsz".to_string(), "l".to_string()),
+                ("image_color".to_string(), "trans".to_string()),
+                ("image_type".to_string(), "clipart".to_string()),
+                ("licenses".to_string(), "cl".to_string()),
+                ("ijn".to_string(), "2".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_images_results_parsing_and_next_page() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "suggested_searches": [{"name": "cute", "chips": "q:ferris,online_chips:cute"}],
+            "images_results": [{
+                "position": 1,
+                "title": "Ferris the crab",
+                "source": "rustacean.net",
+                "original": "https://rustacean.net/ferris.png",
+                "original_width": 1200,
+                "original_height": 800,
+                "related_content_id": "RC1"
+            }],
+            "serpapi_pagination": {"current": 0, "next": "https://serpapi.com/search.json?ijn=1"}
+        }"#;
+        let results: GoogleImagesResults = serde_json::from_str(json).unwrap();
+        let image = &results.images_results.as_ref().unwrap()[0];
+        assert_eq!(image.original_width, Some(1200));
+        assert_eq!(image.related_content_id.as_deref(), Some("RC1"));
+
+        let next = GoogleImagesQuery::new("ferris")
+            .next_page(&results)
+            .unwrap();
+        assert_eq!(next.ijn, Some(1));
+    }
+}
@@ -0,0 +1,406 @@
+#![allow(missing_docs)]
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+
+use crate::{
+    client::SerpClient,
+    engines::{EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    response::{SearchMetadata, SerpapiPagination},
+    streaming::StreamConfig,
+};
+
+/// Query for the `google_jobs` engine.
+///
+/// Pages are linked through `next_page_token`; use
+/// [`SerpClient::jobs_stream`] to iterate over every job.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use serp_sdk::{SerpClient, StreamConfig};
+/// use serp_sdk::engines::google_jobs::GoogleJobsQuery;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = GoogleJobsQuery::new("rust developer")
+///     .location("Berlin, Germany")
+///     .remote_only();
+///
+/// let mut jobs = client.jobs_stream(query, StreamConfig::new().max_pages(3));
+/// while let Some(job) = jobs.next().await {
+///     let job = job?;
+///     println!("{} at {}", job.title, job.company_name.unwrap_or_default());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleJobsQuery {
+    q: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uule: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    google_domain: Option<String>,
+
+    #[serde(rename = "gl", skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chips: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uds: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ltype: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lrad: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_page_token: Option<String>,
+}
+
+impl GoogleJobsQuery {
+    /// Create a job search query
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            q: query.into(),
+            location: None,
+            uule: None,
+            google_domain: None,
+            country: None,
+            language: None,
+            chips: None,
+            uds: None,
+            ltype: None,
+            lrad: None,
+            next_page_token: None,
+        }
+    }
+
+    /// Set the location to search jobs around
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    /// Set an encoded location (uule parameter)
+    ///
+    /// See [`geo::encode_uule`](crate::geo::encode_uule) for encoding canonical names.
+    pub fn uule(mut self, uule: impl Into<String>) -> Self {
+        self.uule = Some(uule.into());
+        self
+    }
+
+    /// Set the Google domain (e.g. "google.co.uk")
+    pub fn domain(mut self, google_domain: impl Into<String>) -> Self {
+        self.google_domain = Some(google_domain.into());
+        self
+    }
+
+    /// Set the country (gl parameter)
+    pub fn country(mut self, gl: impl Into<String>) -> Self {
+        self.country = Some(gl.into());
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Add a filter chip (chips parameter)
+    /// Examples: "date_posted:week", "employment_type:FULLTIME", "city:Owg_06VPwoli_nfhBo8LyA=="
+    ///
+    /// Chip values are listed in the `chips` field of a previous response.
+    pub fn chip(mut self, chip: impl Into<String>) -> Self {
+        let chip = chip.into();
+        self.chips = Some(match self.chips.take() {
+            Some(chips) => format!("{},{}", chips, chip),
+            None => chip,
+        });
+        self
+    }
+
+    /// Set an encoded filter from the `filters` of a previous response (uds parameter)
+    pub fn filter(mut self, uds: impl Into<String>) -> Self {
+        self.uds = Some(uds.into());
+        self
+    }
+
+    /// Only include jobs that can be done remotely (ltype=1)
+    pub fn remote_only(mut self) -> Self {
+        self.ltype = Some(1);
+        self
+    }
+
+    /// Set the search radius around the location, in kilometers (lrad parameter)
+    pub fn radius_km(mut self, lrad: u32) -> SerpResult<Self> {
+        if lrad == 0 {
+            return Err(SerpError::InvalidParameter(
+                "radius must be greater than 0".to_string(),
+            ));
+        }
+        self.lrad = Some(lrad);
+        Ok(self)
+    }
+
+    /// Continue from a page token returned by a previous response
+    pub fn page_token(mut self, token: impl Into<String>) -> Self {
+        self.next_page_token = Some(token.into());
+        self
+    }
+}
+
+impl EngineQuery for GoogleJobsQuery {
+    type Response = GoogleJobsResults;
+
+    fn engine(&self) -> &str {
+        "google_jobs"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        if self.location.is_some() && self.uule.is_some() {
+            return Err(SerpError::InvalidParameter(
+                "location and uule cannot be used together".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn next_page(&self, response: &GoogleJobsResults) -> Option<Self> {
+        response
+            .serpapi_pagination
+            .as_ref()
+            .and_then(|p| p.next_page_token.clone())
+            .map(|token| self.clone().page_token(token))
+    }
+}
+
+/// Query for the `google_jobs_listing` engine.
+///
+/// Returns apply options, salary estimates and employer ratings of a job.
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleJobsListingQuery {
+    q: String,
+}
+
+impl GoogleJobsListingQuery {
+    /// Create a listing query for a `job_id` from a job search result
+    pub fn new(job_id: impl Into<String>) -> Self {
+        Self { q: job_id.into() }
+    }
+}
+
+impl EngineQuery for GoogleJobsListingQuery {
+    type Response = GoogleJobsListingResults;
+
+    fn engine(&self) -> &str {
+        "google_jobs_listing"
+    }
+}
+
+impl SerpClient {
+    /// Stream individual Google Jobs results across pages
+    ///
+    /// Pages are followed through `next_page_token` until SerpAPI stops returning
+    /// one or `config.max_pages` is reached.
+    pub fn jobs_stream(
+        &self,
+        query: GoogleJobsQuery,
+        config: StreamConfig,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<JobResult>> + Send + '_>> {
+        self.engine_items_stream(query, config, |page| page.jobs_results.unwrap_or_default())
+    }
+}
+
+/// Results of a `google_jobs` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleJobsResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub jobs_results: Option<Vec<JobResult>>,
+    pub chips: Option<Vec<JobChip>>,
+    pub filters: Option<Vec<JobFilter>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Job search result
+#[derive(Debug, Deserialize, Clone)]
+pub struct JobResult {
+    pub title: String,
+    pub company_name: Option<String>,
+    pub location: Option<String>,
+    pub via: Option<String>,
+    pub share_link: Option<String>,
+    pub thumbnail: Option<String>,
+    pub extensions: Option<Vec<String>>,
+    pub detected_extensions: Option<JobDetectedExtensions>,
+    pub description: Option<String>,
+    pub job_highlights: Option<Vec<JobHighlight>>,
+    pub apply_options: Option<Vec<ApplyOption>>,
+    pub job_id: Option<String>,
+}
+
+/// Structured facts extracted from a job's extensions
+#[derive(Debug, Deserialize, Clone)]
+pub struct JobDetectedExtensions {
+    /// e.g. "3 days ago"
+    pub posted_at: Option<String>,
+    /// e.g. "Full-time"
+    pub schedule_type: Option<String>,
+    /// e.g. "80K–100K a year"
+    pub salary: Option<String>,
+    pub work_from_home: Option<bool>,
+    pub qualifications: Option<String>,
+    pub health_insurance: Option<bool>,
+    pub dental_coverage: Option<bool>,
+    pub paid_time_off: Option<bool>,
+}
+
+/// Highlighted section of a job description
+#[derive(Debug, Deserialize, Clone)]
+pub struct JobHighlight {
+    pub title: Option<String>,
+    pub items: Vec<String>,
+}
+
+/// Site where a job can be applied to
+#[derive(Debug, Deserialize, Clone)]
+pub struct ApplyOption {
+    pub title: String,
+    pub link: String,
+}
+
+/// Filter chip offered for refining a job search
+#[derive(Debug, Deserialize, Clone)]
+pub struct JobChip {
+    #[serde(rename = "type")]
+    pub chip_type: String,
+    pub param: Option<String>,
+    pub options: Vec<JobChipOption>,
+}
+
+/// Value of a filter chip
+#[derive(Debug, Deserialize, Clone)]
+pub struct JobChipOption {
+    pub text: String,
+    /// Value to pass to [`GoogleJobsQuery::chip`]
+    pub value: Option<String>,
+}
+
+/// Encoded filter offered for refining a job search
+#[derive(Debug, Deserialize, Clone)]
+pub struct JobFilter {
+    pub name: String,
+    /// Value to pass to [`GoogleJobsQuery::filter`]
+    pub uds: Option<String>,
+    pub q: Option<String>,
+    pub link: Option<String>,
+    pub serpapi_link: Option<String>,
+}
+
+/// Results of a `google_jobs_listing` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleJobsListingResults {
+    pub search_metadata: SearchMetadata,
+    pub apply_options: Option<Vec<ApplyOption>>,
+    pub salaries: Option<Vec<JobSalary>>,
+    pub ratings: Option<Vec<EmployerRating>>,
+}
+
+/// Salary estimate for a job
+#[derive(Debug, Deserialize, Clone)]
+pub struct JobSalary {
+    pub job_title: Option<String>,
+    pub link: Option<String>,
+    pub source: Option<String>,
+    pub source_link: Option<String>,
+    pub thumbnail: Option<String>,
+    pub salary_from: Option<f64>,
+    pub salary_to: Option<f64>,
+    pub salary_currency: Option<String>,
+    /// e.g. "year" or "hour"
+    pub salary_periodic: Option<String>,
+    pub based_on: Option<String>,
+}
+
+/// Employer rating on a review site
+#[derive(Debug, Deserialize, Clone)]
+pub struct EmployerRating {
+    pub source: String,
+    pub link: Option<String>,
+    pub rating: Option<f32>,
+    pub reviews: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jobs_query_params() {
+        let query = GoogleJobsQuery::new("barista")
+            .location("Austin, Texas")
+            .chip("date_posted:week")
+            .chip("employment_type:PARTTIME")
+            .remote_only()
+            .radius_km(25)
+            .unwrap();
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("q".to_string(), "barista".to_string()),
+                ("location".to_string(), "Austin, Texas".to_string()),
+                (
+                    "chips".to_string(),
+                    "date_posted:week,employment_type:PARTTIME".to_string()
+                ),
+                ("ltype".to_string(), "1".to_string()),
+                ("lrad".to_string(), "25".to_string()),
+            ]
+        );
+        assert!(query.clone().uule("w+CAIQICI").validate().is_err());
+    }
+
+    #[test]
+    fn test_jobs_results_parsing_and_next_page() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "jobs_results": [{
+                "title": "Barista",
+                "company_name": "Coffee Co",
+                "location": "Austin, TX",
+                "via": "LinkedIn",
+                "extensions": ["2 days ago", "Part-time"],
+                "detected_extensions": {"posted_at": "2 days ago", "schedule_type": "Part-time", "salary": "15–18 an hour"},
+                "apply_options": [{"title": "LinkedIn", "link": "https://linkedin.com/jobs/1"}],
+                "job_id": "eyJqb2"
+            }],
+            "serpapi_pagination": {"next_page_token": "TOKEN2"}
+        }"#;
+        let results: GoogleJobsResults = serde_json::from_str(json).unwrap();
+        let job = &results.jobs_results.as_ref().unwrap()[0];
+        let detected = job.detected_extensions.as_ref().unwrap();
+        assert_eq!(detected.schedule_type.as_deref(), Some("Part-time"));
+        assert_eq!(detected.salary.as_deref(), Some("15–18 an hour"));
+
+        let next = GoogleJobsQuery::new("barista").next_page(&results).unwrap();
+        assert_eq!(next.next_page_token.as_deref(), Some("TOKEN2"));
+    }
+}
@@ -0,0 +1,252 @@
+#![allow(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    engines::{EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    response::{InlineImage, Price, SearchMetadata},
+};
+
+/// Kind of matches a Lens search returns (type parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LensSearchType {
+    All,
+    Products,
+    ExactMatches,
+    VisualMatches,
+}
+
+/// Query for the `google_lens` engine.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::SerpClient;
+/// use serp_sdk::engines::google_lens::{GoogleLensQuery, LensSearchType};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = GoogleLensQuery::new("https://example.com/photo.jpg")
+///     .search_type(LensSearchType::ExactMatches);
+///
+/// let results = client.search_engine(query).await?;
+/// for image in results.exact_matches.unwrap_or_default() {
+///     println!("{:?} {:?}", image.image.source, image.image.link);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleLensQuery {
+    url: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    q: Option<String>,
+
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    search_type: Option<LensSearchType>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    safe: Option<String>,
+}
+
+impl GoogleLensQuery {
+    /// Create a Lens search for a publicly reachable image URL
+    pub fn new(image_url: impl Into<String>) -> Self {
+        Self {
+            url: image_url.into(),
+            q: None,
+            search_type: None,
+            language: None,
+            country: None,
+            safe: None,
+        }
+    }
+
+    /// Refine visual matches with text (q parameter)
+    pub fn refine(mut self, query: impl Into<String>) -> Self {
+        self.q = Some(query.into());
+        self
+    }
+
+    /// Set the kind of matches to return (type parameter)
+    pub fn search_type(mut self, search_type: LensSearchType) -> Self {
+        self.search_type = Some(search_type);
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Set the country (country parameter)
+    pub fn country(mut self, country: impl Into<String>) -> Self {
+        self.country = Some(country.into());
+        self
+    }
+
+    /// Set safe search (safe parameter)
+    /// Values: "active", "off"
+    pub fn safe_search(mut self, safe: impl Into<String>) -> Self {
+        self.safe = Some(safe.into());
+        self
+    }
+}
+
+impl EngineQuery for GoogleLensQuery {
+    type Response = GoogleLensResults;
+
+    fn engine(&self) -> &str {
+        "google_lens"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        check_image_url(&self.url)
+    }
+}
+
+/// Check that an image to search by is an absolute http(s) URL
+pub(crate) fn check_image_url(image_url: &str) -> SerpResult<()> {
+    let parsed = url::Url::parse(image_url).map_err(|e| {
+        SerpError::InvalidParameter(format!("invalid image URL '{}': {}", image_url, e))
+    })?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(SerpError::InvalidParameter(format!(
+            "image URL must use http or https, got '{}'",
+            image_url
+        )));
+    }
+    Ok(())
+}
+
+/// Results of a `google_lens` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleLensResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub knowledge_graph: Option<Vec<LensKnowledgeGraph>>,
+    pub visual_matches: Option<Vec<VisualMatch>>,
+    pub exact_matches: Option<Vec<VisualMatch>>,
+    pub products: Option<Vec<VisualMatch>>,
+    pub related_content: Option<Vec<LensRelatedContent>>,
+}
+
+/// Page showing an image that matches the searched one
+#[derive(Debug, Deserialize, Clone)]
+pub struct VisualMatch {
+    /// Position, page title and link, source site and image URLs
+    #[serde(flatten)]
+    pub image: InlineImage,
+    pub source_icon: Option<String>,
+    pub thumbnail_width: Option<u32>,
+    pub thumbnail_height: Option<u32>,
+    pub image_width: Option<u32>,
+    pub image_height: Option<u32>,
+    pub date: Option<String>,
+    pub price: Option<LensPrice>,
+    pub in_stock: Option<bool>,
+    pub condition: Option<String>,
+    pub rating: Option<f32>,
+    pub reviews: Option<u32>,
+}
+
+impl VisualMatch {
+    /// Price of a product match as amount and currency
+    pub fn parsed_price(&self) -> Option<Price> {
+        let price = self.price.as_ref()?;
+        Price::from_parts(price.value.as_deref(), price.extracted_value)
+    }
+}
+
+/// Price shown on a product match
+#[derive(Debug, Deserialize, Clone)]
+pub struct LensPrice {
+    pub value: Option<String>,
+    pub extracted_value: Option<f64>,
+    pub currency: Option<String>,
+}
+
+/// Entity Lens recognized in the image
+#[derive(Debug, Deserialize, Clone)]
+pub struct LensKnowledgeGraph {
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub link: Option<String>,
+    pub more_info: Option<String>,
+    pub thumbnail: Option<String>,
+    pub images: Option<Vec<InlineImage>>,
+}
+
+/// Search related to the image
+#[derive(Debug, Deserialize, Clone)]
+pub struct LensRelatedContent {
+    pub query: String,
+    pub link: Option<String>,
+    pub thumbnail: Option<String>,
+    pub serpapi_link: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lens_query_params() {
+        let query = GoogleLensQuery::new("https://example.com/a.jpg")
+            .search_type(LensSearchType::VisualMatches)
+            .country("us");
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("url".to_string(), "https://example.com/a.jpg".to_string()),
+                ("type".to_string(), "visual_matches".to_string()),
+                ("country".to_string(), "us".to_string()),
+            ]
+        );
+        assert!(query.validate().is_ok());
+        assert!(GoogleLensQuery::new("file:///tmp/a.jpg")
+            .validate()
+            .is_err());
+        assert!(GoogleLensQuery::new("a.jpg").validate().is_err());
+    }
+
+    #[test]
+    fn test_lens_results_parsing() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "visual_matches": [{
+                "position": 1,
+                "title": "Red sneakers",
+                "link": "https://shop.example.com/sneakers",
+                "source": "shop.example.com",
+                "source_icon": "https://shop.example.com/favicon.ico",
+                "thumbnail": "https://encrypted-tbn0.gstatic.com/x",
+                "image": "https://shop.example.com/sneakers.jpg",
+                "price": {"value": "$59.99*", "extracted_value": 59.99, "currency": "$"},
+                "in_stock": true
+            }],
+            "related_content": [{"query": "red sneakers", "serpapi_link": "https://serpapi.com/x"}]
+        }"#;
+        let results: GoogleLensResults = serde_json::from_str(json).unwrap();
+        let hit = &results.visual_matches.as_ref().unwrap()[0];
+        assert_eq!(hit.image.source.as_deref(), Some("shop.example.com"));
+        assert_eq!(
+            hit.image.original.as_deref(),
+            Some("https://shop.example.com/sneakers.jpg")
+        );
+        let price = hit.parsed_price().unwrap();
+        assert_eq!(price.amount, 59.99);
+        assert_eq!(price.currency.as_deref(), Some("USD"));
+    }
+}
@@ -0,0 +1,143 @@
+#![allow(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    engines::{EngineParameters, EngineQuery},
+    response::{GpsCoordinates, SearchMetadata},
+};
+
+/// Query for the `google_local_services` engine.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::SerpClient;
+/// use serp_sdk::engines::google_local_services::GoogleLocalServicesQuery;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = GoogleLocalServicesQuery::new("electrician", "6745062158417646970")
+///     .job_type("panel_upgrade");
+///
+/// let results = client.search_engine(query).await?;
+/// for provider in results.local_ads.unwrap_or_default() {
+///     println!("{} {:?} {:?}", provider.title, provider.badge, provider.rating);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleLocalServicesQuery {
+    q: String,
+
+    data_cid: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    job_type: Option<String>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+}
+
+impl GoogleLocalServicesQuery {
+    /// Create a local services search for a service in the area of a place CID
+    pub fn new(query: impl Into<String>, data_cid: impl Into<String>) -> Self {
+        Self {
+            q: query.into(),
+            data_cid: data_cid.into(),
+            job_type: None,
+            language: None,
+        }
+    }
+
+    /// Narrow results to providers offering a job type (job_type parameter)
+    pub fn job_type(mut self, job_type: impl Into<String>) -> Self {
+        self.job_type = Some(job_type.into());
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+}
+
+impl EngineQuery for GoogleLocalServicesQuery {
+    type Response = GoogleLocalServicesResults;
+
+    fn engine(&self) -> &str {
+        "google_local_services"
+    }
+}
+
+/// Results of a `google_local_services` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleLocalServicesResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub local_ads: Option<Vec<LocalServiceProvider>>,
+}
+
+/// Service provider listed by Google Local Services
+#[derive(Debug, Deserialize, Clone)]
+pub struct LocalServiceProvider {
+    pub position: Option<u32>,
+    pub title: String,
+    pub link: Option<String>,
+    /// e.g. "GOOGLE GUARANTEED" or "GOOGLE SCREENED"
+    pub badge: Option<String>,
+    pub rating: Option<f64>,
+    #[serde(alias = "rating_count")]
+    pub reviews: Option<u32>,
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+    pub phone: Option<String>,
+    pub address: Option<String>,
+    pub gps_coordinates: Option<GpsCoordinates>,
+    /// e.g. "Serves Austin"
+    pub service_area: Option<String>,
+    pub hours: Option<String>,
+    pub years_in_business: Option<u32>,
+    /// e.g. "5 jobs booked nearby"
+    pub bookings_nearby: Option<String>,
+    pub thumbnail: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_local_services_params_and_parsing() {
+        let query = GoogleLocalServicesQuery::new("plumber", "123").job_type("drain_cleaning");
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("q".to_string(), "plumber".to_string()),
+                ("data_cid".to_string(), "123".to_string()),
+                ("job_type".to_string(), "drain_cleaning".to_string()),
+            ]
+        );
+
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "local_ads": [{
+                "position": 1,
+                "title": "Acme Plumbing",
+                "badge": "GOOGLE GUARANTEED",
+                "rating": 4.9,
+                "rating_count": 312,
+                "service_area": "Serves Austin",
+                "years_in_business": 12,
+                "gps_coordinates": {"latitude": 30.27, "longitude": -97.74}
+            }]
+        }"#;
+        let results: GoogleLocalServicesResults = serde_json::from_str(json).unwrap();
+        let provider = &results.local_ads.as_ref().unwrap()[0];
+        assert_eq!(provider.reviews, Some(312));
+        assert_eq!(provider.gps_coordinates.as_ref().unwrap().latitude, 30.27);
+    }
+}
@@ -0,0 +1,685 @@
+#![allow(missing_docs)]
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::pin::Pin;
+
+use crate::{
+    client::SerpClient,
+    engines::{has_next_page, EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    geo::GeoPoint,
+    response::{GpsCoordinates, LocalPlace, SearchMetadata, SerpapiPagination},
+    streaming::StreamConfig,
+};
+
+/// Kind of Google Maps search (type parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MapsSearchType {
+    /// List of places matching a query
+    Search,
+    /// Details of a single place
+    Place,
+}
+
+/// Query for the `google_maps` engine.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::{geo::GeoPoint, SerpClient};
+/// use serp_sdk::engines::google_maps::GoogleMapsQuery;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = GoogleMapsQuery::search("pizza")
+///     .ll(GeoPoint::new(40.7455096, -74.0083012)?, 14)?
+///     .language("en");
+///
+/// let results = client.search_engine(query).await?;
+/// for place in results.local_results.unwrap_or_default() {
+///     println!("{}: {:?}", place.title, place.data_id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleMapsQuery {
+    #[serde(rename = "type")]
+    search_type: MapsSearchType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    q: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ll: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    place_id: Option<String>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(rename = "gl", skip_serializing_if = "Option::is_none")]
+    geolocation: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    google_domain: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start: Option<u32>,
+}
+
+impl GoogleMapsQuery {
+    fn with_type(search_type: MapsSearchType) -> Self {
+        Self {
+            search_type,
+            q: None,
+            ll: None,
+            data: None,
+            place_id: None,
+            language: None,
+            geolocation: None,
+            google_domain: None,
+            start: None,
+        }
+    }
+
+    /// Search for places matching a query (type=search)
+    pub fn search(query: impl Into<String>) -> Self {
+        Self {
+            q: Some(query.into()),
+            ..Self::with_type(MapsSearchType::Search)
+        }
+    }
+
+    /// Get details of a place by its Google place ID (type=place)
+    pub fn place(place_id: impl Into<String>) -> Self {
+        Self {
+            place_id: Some(place_id.into()),
+            ..Self::with_type(MapsSearchType::Place)
+        }
+    }
+
+    /// Get details of a place by its data ID and coordinates (type=place)
+    pub fn place_by_data_id(data_id: &str, point: GeoPoint) -> Self {
+        Self {
+            data: Some(format!(
+                "!4m5!3m4!1s{}!8m2!3d{}!4d{}",
+                data_id,
+                point.latitude(),
+                point.longitude()
+            )),
+            ..Self::with_type(MapsSearchType::Place)
+        }
+    }
+
+    /// Set GPS coordinates and zoom level (ll parameter)
+    /// Zoom ranges from 3 (world) to 21 (street)
+    pub fn ll(mut self, point: GeoPoint, zoom: u8) -> SerpResult<Self> {
+        self.ll = Some(point.ll(zoom)?);
+        Ok(self)
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Set the country (gl parameter)
+    pub fn country(mut self, gl: impl Into<String>) -> Self {
+        self.geolocation = Some(gl.into());
+        self
+    }
+
+    /// Set the Google domain to use
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.google_domain = Some(domain.into());
+        self
+    }
+
+    /// Set the result offset for pagination (start parameter, multiples of 20)
+    pub fn offset(mut self, start: u32) -> Self {
+        self.start = Some(start);
+        self
+    }
+}
+
+impl EngineQuery for GoogleMapsQuery {
+    type Response = GoogleMapsResults;
+
+    fn engine(&self) -> &str {
+        "google_maps"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        match self.search_type {
+            MapsSearchType::Search if self.q.as_deref().map_or(true, str::is_empty) => Err(
+                SerpError::InvalidParameter("maps search requires a query".to_string()),
+            ),
+            MapsSearchType::Place if self.data.is_none() && self.place_id.is_none() => {
+                Err(SerpError::InvalidParameter(
+                    "maps place lookup requires a place_id or data_id".to_string(),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn next_page(&self, response: &GoogleMapsResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        let count = response.local_results.as_ref().map_or(0, Vec::len) as u32;
+
+        if self.search_type != MapsSearchType::Search || !has_next || count == 0 {
+            return None;
+        }
+        Some(self.clone().offset(self.start.unwrap_or(0) + count))
+    }
+}
+
+/// Sort order for Google Maps reviews (sort_by parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum MapsReviewSort {
+    /// Most relevant first (default)
+    #[serde(rename = "qualityScore")]
+    Relevance,
+    /// Most recent first
+    #[serde(rename = "newestFirst")]
+    Newest,
+    /// Highest rating first
+    #[serde(rename = "ratingHigh")]
+    HighestRating,
+    /// Lowest rating first
+    #[serde(rename = "ratingLow")]
+    LowestRating,
+}
+
+/// Query for the `google_maps_reviews` engine.
+///
+/// Pages are linked through `next_page_token`; use
+/// [`SerpClient::maps_reviews_stream`] to iterate over every review.
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleMapsReviewsQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    place_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort_by: Option<MapsReviewSort>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    topic_id: Option<String>,
+
+    #[serde(rename = "query", skip_serializing_if = "Option::is_none")]
+    filter: Option<String>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_page_token: Option<String>,
+}
+
+impl GoogleMapsReviewsQuery {
+    /// Create a reviews query for a place data ID (e.g. "0x89c259af336b3341:0xa4969e07ce3108de")
+    pub fn new(data_id: impl Into<String>) -> Self {
+        Self {
+            data_id: Some(data_id.into()),
+            place_id: None,
+            sort_by: None,
+            topic_id: None,
+            filter: None,
+            language: None,
+            num: None,
+            next_page_token: None,
+        }
+    }
+
+    /// Create a reviews query for a Google place ID
+    pub fn for_place_id(place_id: impl Into<String>) -> Self {
+        Self {
+            data_id: None,
+            place_id: Some(place_id.into()),
+            ..Self::new("")
+        }
+    }
+
+    /// Set the sort order
+    pub fn sort_by(mut self, sort: MapsReviewSort) -> Self {
+        self.sort_by = Some(sort);
+        self
+    }
+
+    /// Only return reviews for a topic (topic_id from the first page's topics)
+    pub fn topic(mut self, topic_id: impl Into<String>) -> Self {
+        self.topic_id = Some(topic_id.into());
+        self
+    }
+
+    /// Only return reviews containing the given text
+    pub fn filter(mut self, text: impl Into<String>) -> Self {
+        self.filter = Some(text.into());
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Set the number of reviews per page (1-20)
+    ///
+    /// SerpAPI always returns 8 reviews on the first page, so this only applies
+    /// to pages fetched with a `next_page_token`.
+    pub fn limit(mut self, num: u32) -> SerpResult<Self> {
+        if num == 0 || num > 20 {
+            return Err(SerpError::InvalidParameter(
+                "limit must be between 1 and 20".to_string(),
+            ));
+        }
+        self.num = Some(num);
+        Ok(self)
+    }
+
+    /// Continue from a page token returned by a previous response
+    pub fn page_token(mut self, token: impl Into<String>) -> Self {
+        self.next_page_token = Some(token.into());
+        self
+    }
+}
+
+impl EngineQuery for GoogleMapsReviewsQuery {
+    type Response = GoogleMapsReviewsResults;
+
+    fn engine(&self) -> &str {
+        "google_maps_reviews"
+    }
+
+    fn params(&self) -> SerpResult<Vec<(String, String)>> {
+        let encoded = serde_urlencoded::to_string(self)?;
+        Ok(url::form_urlencoded::parse(encoded.as_bytes())
+            .into_owned()
+            .filter(|(key, _)| key != "num" || self.next_page_token.is_some())
+            .collect())
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        if self.data_id.as_deref().map_or(true, str::is_empty) && self.place_id.is_none() {
+            return Err(SerpError::InvalidParameter(
+                "maps reviews require a data_id or place_id".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn next_page(&self, response: &GoogleMapsReviewsResults) -> Option<Self> {
+        response
+            .serpapi_pagination
+            .as_ref()
+            .and_then(|p| p.next_page_token.clone())
+            .map(|token| self.clone().page_token(token))
+    }
+}
+
+/// Query for the `google_maps_photos` engine.
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleMapsPhotosQuery {
+    data_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    category_id: Option<String>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_page_token: Option<String>,
+}
+
+impl GoogleMapsPhotosQuery {
+    /// Create a photos query for a place data ID
+    pub fn new(data_id: impl Into<String>) -> Self {
+        Self {
+            data_id: data_id.into(),
+            category_id: None,
+            language: None,
+            next_page_token: None,
+        }
+    }
+
+    /// Only return photos of a category (id from the response's categories)
+    pub fn category(mut self, category_id: impl Into<String>) -> Self {
+        self.category_id = Some(category_id.into());
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Continue from a page token returned by a previous response
+    pub fn page_token(mut self, token: impl Into<String>) -> Self {
+        self.next_page_token = Some(token.into());
+        self
+    }
+}
+
+impl EngineQuery for GoogleMapsPhotosQuery {
+    type Response = GoogleMapsPhotosResults;
+
+    fn engine(&self) -> &str {
+        "google_maps_photos"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        if self.data_id.is_empty() {
+            return Err(SerpError::InvalidParameter(
+                "maps photos require a data_id".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn next_page(&self, response: &GoogleMapsPhotosResults) -> Option<Self> {
+        response
+            .serpapi_pagination
+            .as_ref()
+            .and_then(|p| p.next_page_token.clone())
+            .map(|token| self.clone().page_token(token))
+    }
+}
+
+impl SerpClient {
+    /// Stream individual Google Maps reviews across pages
+    ///
+    /// Pages are followed through `next_page_token` until SerpAPI stops returning
+    /// one or `config.max_pages` is reached.
+    pub fn maps_reviews_stream(
+        &self,
+        query: GoogleMapsReviewsQuery,
+        config: StreamConfig,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<MapsReview>> + Send + '_>> {
+        self.engine_items_stream(query, config, |page| page.reviews.unwrap_or_default())
+    }
+}
+
+/// Results of a `google_maps` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleMapsResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    /// Places matching a type=search query
+    pub local_results: Option<Vec<LocalPlace>>,
+    /// Place details for a type=place query
+    pub place_results: Option<MapsPlaceDetails>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Details of a single place, returned for a type=place query
+#[derive(Debug, Deserialize, Clone)]
+pub struct MapsPlaceDetails {
+    pub title: String,
+    pub place_id: Option<String>,
+    pub data_id: Option<String>,
+    pub data_cid: Option<String>,
+    pub reviews_link: Option<String>,
+    pub photos_link: Option<String>,
+    pub gps_coordinates: Option<GpsCoordinates>,
+    pub provider_id: Option<String>,
+    pub rating: Option<f64>,
+    pub reviews: Option<u32>,
+    pub price: Option<String>,
+    /// Place categories, e.g. ["Coffee shop", "Cafe"]
+    #[serde(rename = "type")]
+    pub types: Option<Vec<String>>,
+    pub type_ids: Option<Vec<String>>,
+    pub description: Option<String>,
+    pub address: Option<String>,
+    pub plus_code: Option<String>,
+    pub phone: Option<String>,
+    pub website: Option<String>,
+    pub open_state: Option<String>,
+    /// One entry per day, e.g. [{"monday": "7 AM–5 PM"}, ...]
+    pub hours: Option<Vec<HashMap<String, String>>>,
+    pub rating_summary: Option<Vec<RatingCount>>,
+    pub images: Option<Vec<PlaceImage>>,
+    pub thumbnail: Option<String>,
+}
+
+impl MapsPlaceDetails {
+    /// Opening hours keyed by lowercase day name
+    pub fn opening_hours(&self) -> HashMap<String, String> {
+        self.hours
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|(day, hours)| (day.clone(), hours.clone()))
+            .collect()
+    }
+}
+
+/// Number of reviews with a given star rating
+#[derive(Debug, Deserialize, Clone)]
+pub struct RatingCount {
+    pub stars: u8,
+    pub amount: Option<u32>,
+}
+
+/// Photo shown on a place's details page
+#[derive(Debug, Deserialize, Clone)]
+pub struct PlaceImage {
+    pub title: Option<String>,
+    pub thumbnail: Option<String>,
+}
+
+/// Results of a `google_maps_reviews` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleMapsReviewsResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub place_info: Option<MapsPlaceInfo>,
+    pub topics: Option<Vec<ReviewTopic>>,
+    pub reviews: Option<Vec<MapsReview>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Summary of the reviewed place
+#[derive(Debug, Deserialize, Clone)]
+pub struct MapsPlaceInfo {
+    pub title: Option<String>,
+    pub address: Option<String>,
+    pub rating: Option<f64>,
+    pub reviews: Option<u32>,
+    #[serde(rename = "type")]
+    pub place_type: Option<String>,
+}
+
+/// Topic mentioned across reviews
+#[derive(Debug, Deserialize, Clone)]
+pub struct ReviewTopic {
+    pub keyword: Option<String>,
+    pub mentions: Option<u32>,
+    pub id: Option<String>,
+}
+
+/// Google Maps review
+#[derive(Debug, Deserialize, Clone)]
+pub struct MapsReview {
+    pub review_id: Option<String>,
+    pub link: Option<String>,
+    pub rating: Option<f64>,
+    pub date: Option<String>,
+    pub iso_date: Option<String>,
+    pub iso_date_of_last_edit: Option<String>,
+    pub snippet: Option<String>,
+    pub extracted_snippet: Option<ExtractedSnippet>,
+    pub likes: Option<u32>,
+    pub user: Option<ReviewUser>,
+    pub images: Option<Vec<String>>,
+    pub response: Option<OwnerResponse>,
+    pub source: Option<String>,
+}
+
+/// Review text in original and translated form
+#[derive(Debug, Deserialize, Clone)]
+pub struct ExtractedSnippet {
+    pub original: Option<String>,
+    pub translated: Option<String>,
+}
+
+/// Author of a review or photo
+#[derive(Debug, Deserialize, Clone)]
+pub struct ReviewUser {
+    pub name: Option<String>,
+    pub link: Option<String>,
+    pub contributor_id: Option<String>,
+    pub thumbnail: Option<String>,
+    pub local_guide: Option<bool>,
+    pub reviews: Option<u32>,
+    pub photos: Option<u32>,
+}
+
+/// Reply from the business owner
+#[derive(Debug, Deserialize, Clone)]
+pub struct OwnerResponse {
+    pub date: Option<String>,
+    pub iso_date: Option<String>,
+    pub snippet: Option<String>,
+}
+
+/// Results of a `google_maps_photos` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleMapsPhotosResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub categories: Option<Vec<PhotoCategory>>,
+    pub photos: Option<Vec<MapsPhoto>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Photo category of a place
+#[derive(Debug, Deserialize, Clone)]
+pub struct PhotoCategory {
+    pub title: Option<String>,
+    pub id: Option<String>,
+}
+
+/// Photo of a place
+#[derive(Debug, Deserialize, Clone)]
+pub struct MapsPhoto {
+    pub thumbnail: Option<String>,
+    pub image: Option<String>,
+    pub photo_meta_serpapi_link: Option<String>,
+    pub user: Option<ReviewUser>,
+    pub video: Option<String>,
+    pub gps_coordinates: Option<GpsCoordinates>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::to_query_string;
+
+    #[test]
+    fn test_maps_query_params() {
+        let point = GeoPoint::new(40.7455096, -74.0083012).unwrap();
+        let query = GoogleMapsQuery::search("pizza")
+            .ll(point, 14)
+            .unwrap()
+            .language("en");
+
+        assert_eq!(
+            to_query_string(&query, "key").unwrap(),
+            "engine=google_maps&type=search&q=pizza&ll=%4040.7455096%2C-74.0083012%2C14z&hl=en&api_key=key"
+        );
+
+        let place = GoogleMapsQuery::place_by_data_id("0x1:0x2", point);
+        assert!(place.validate().is_ok());
+        assert!(GoogleMapsQuery::search("").validate().is_err());
+    }
+
+    #[test]
+    fn test_reviews_pagination() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "place_info": {"title": "Joe's Pizza", "rating": 4.5, "reviews": 1200},
+            "reviews": [{
+                "rating": 5.0,
+                "iso_date": "2024-01-01T00:00:00Z",
+                "snippet": "Great slice",
+                "user": {"name": "Ann", "local_guide": true},
+                "response": {"snippet": "Thanks!"}
+            }],
+            "serpapi_pagination": {"next_page_token": "TOKEN2"}
+        }"#;
+        let page: GoogleMapsReviewsResults = serde_json::from_str(json).unwrap();
+        assert_eq!(page.reviews.as_ref().unwrap().len(), 1);
+
+        let query = GoogleMapsReviewsQuery::new("0x1:0x2").limit(20).unwrap();
+        assert!(!query.params().unwrap().iter().any(|(k, _)| k == "num"));
+
+        let next = query.next_page(&page).unwrap();
+        let params = next.params().unwrap();
+        assert!(params.contains(&("next_page_token".to_string(), "TOKEN2".to_string())));
+        assert!(params.contains(&("num".to_string(), "20".to_string())));
+    }
+
+    #[test]
+    fn test_local_place_optional_fields() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "local_results": [{"position": 1, "title": "Joe's Pizza", "type": "Pizza restaurant"}],
+            "serpapi_pagination": {"next": "https://serpapi.com/search.json?start=20"}
+        }"#;
+        let page: GoogleMapsResults = serde_json::from_str(json).unwrap();
+        let places = page.local_results.as_ref().unwrap();
+        assert_eq!(places[0].type_.as_deref(), Some("Pizza restaurant"));
+
+        let next = GoogleMapsQuery::search("pizza").next_page(&page).unwrap();
+        assert_eq!(next.start, Some(1));
+    }
+
+    #[test]
+    fn test_place_details_parsing() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "place_results": {
+                "title": "Blue Bottle Coffee",
+                "data_id": "0x89c259a61c75684f:0x79d31adb123348d2",
+                "rating": 4.6,
+                "reviews": 1523,
+                "type": ["Coffee shop", "Cafe"],
+                "hours": [{"monday": "7 AM–5 PM"}, {"tuesday": "7 AM–5 PM"}],
+                "rating_summary": [{"stars": 5, "amount": 1100}, {"stars": 1, "amount": 40}]
+            }
+        }"#;
+        let page: GoogleMapsResults = serde_json::from_str(json).unwrap();
+        let place = page.place_results.unwrap();
+        assert_eq!(place.types.as_deref().unwrap()[1], "Cafe");
+        assert_eq!(
+            place.opening_hours().get("tuesday").map(String::as_str),
+            Some("7 AM–5 PM")
+        );
+
+        let photos: GoogleMapsPhotosResults = serde_json::from_str(
+            r#"{"search_metadata": {"id": "abc"}, "categories": [{"title": "All"}]}"#,
+        )
+        .unwrap();
+        assert!(photos.categories.unwrap()[0].id.is_none());
+    }
+}
@@ -0,0 +1,340 @@
+#![allow(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    engines::{EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    parse,
+    response::SearchMetadata,
+};
+
+/// Query for the `google_news` engine.
+///
+/// Unlike the `tbm=nws` tab of a regular search, the dedicated engine can browse
+/// topics, publications and sections, and expand a story into its full coverage.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::SerpClient;
+/// use serp_sdk::engines::google_news::GoogleNewsQuery;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let results = client.search_engine(GoogleNewsQuery::new("rust language")).await?;
+///
+/// for result in results.news_results.unwrap_or_default() {
+///     if let Some(token) = &result.story_token {
+///         let coverage = client
+///             .search_engine(GoogleNewsQuery::story(token.clone()).sort_by_date())
+///             .await?;
+///         println!("{} articles", coverage.news_results.map_or(0, |r| r.len()));
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleNewsQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    q: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    topic_token: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    publication_token: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    section_token: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    story_token: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kgmid: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    so: Option<u8>,
+
+    #[serde(rename = "gl", skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+}
+
+impl GoogleNewsQuery {
+    fn empty() -> Self {
+        Self {
+            q: None,
+            topic_token: None,
+            publication_token: None,
+            section_token: None,
+            story_token: None,
+            kgmid: None,
+            so: None,
+            country: None,
+            language: None,
+        }
+    }
+
+    /// Search news articles
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            q: Some(query.into()),
+            ..Self::empty()
+        }
+    }
+
+    /// Browse a topic such as "Technology" (token from `menu_links`)
+    pub fn topic(topic_token: impl Into<String>) -> Self {
+        Self {
+            topic_token: Some(topic_token.into()),
+            ..Self::empty()
+        }
+    }
+
+    /// Browse a publication such as "CNN"
+    pub fn publication(publication_token: impl Into<String>) -> Self {
+        Self {
+            publication_token: Some(publication_token.into()),
+            ..Self::empty()
+        }
+    }
+
+    /// Get the full coverage of a story (token from a result's `story_token`)
+    pub fn story(story_token: impl Into<String>) -> Self {
+        Self {
+            story_token: Some(story_token.into()),
+            ..Self::empty()
+        }
+    }
+
+    /// Browse news about a Knowledge Graph entity (e.g. "/m/02vqfm")
+    pub fn entity(kgmid: impl Into<String>) -> Self {
+        Self {
+            kgmid: Some(kgmid.into()),
+            ..Self::empty()
+        }
+    }
+
+    /// Narrow a topic or publication to a section (token from `sub_menu_links`)
+    pub fn section(mut self, section_token: impl Into<String>) -> Self {
+        self.section_token = Some(section_token.into());
+        self
+    }
+
+    /// Sort full-coverage articles by date instead of relevance (so=1)
+    pub fn sort_by_date(mut self) -> Self {
+        self.so = Some(1);
+        self
+    }
+
+    /// Set the country (gl parameter)
+    pub fn country(mut self, gl: impl Into<String>) -> Self {
+        self.country = Some(gl.into());
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+}
+
+impl EngineQuery for GoogleNewsQuery {
+    type Response = GoogleNewsResults;
+
+    fn engine(&self) -> &str {
+        "google_news"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        let sources = [
+            self.q.is_some(),
+            self.topic_token.is_some(),
+            self.publication_token.is_some(),
+            self.story_token.is_some(),
+            self.kgmid.is_some(),
+        ];
+        if sources.iter().filter(|set| **set).count() > 1 {
+            return Err(SerpError::InvalidParameter(
+                "q, topic_token, publication_token, story_token and kgmid are mutually exclusive"
+                    .to_string(),
+            ));
+        }
+        if self.section_token.is_some()
+            && self.topic_token.is_none()
+            && self.publication_token.is_none()
+        {
+            return Err(SerpError::InvalidParameter(
+                "section_token requires a topic_token or publication_token".to_string(),
+            ));
+        }
+        if self.so.is_some() && self.story_token.is_none() {
+            return Err(SerpError::InvalidParameter(
+                "sorting is only available for story_token queries".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Results of a `google_news` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleNewsResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub title: Option<String>,
+    pub news_results: Option<Vec<NewsResult>>,
+    pub menu_links: Option<Vec<NewsMenuLink>>,
+    pub sub_menu_links: Option<Vec<NewsMenuLink>>,
+    pub top_stories_link: Option<NewsMenuLink>,
+}
+
+/// Google News result: either a single article or a story cluster
+#[derive(Debug, Deserialize, Clone)]
+pub struct NewsResult {
+    pub position: Option<u32>,
+    pub title: Option<String>,
+    pub source: Option<NewsSource>,
+    pub link: Option<String>,
+    pub thumbnail: Option<String>,
+    pub thumbnail_small: Option<String>,
+    pub date: Option<String>,
+    pub iso_date: Option<String>,
+    /// Token for the full coverage of the story
+    pub story_token: Option<String>,
+    pub serpapi_link: Option<String>,
+    /// Lead article of a story cluster
+    pub highlight: Option<NewsArticle>,
+    /// Other articles of a story cluster
+    pub stories: Option<Vec<NewsArticle>>,
+}
+
+impl NewsResult {
+    /// Whether this result groups several articles about one story
+    pub fn is_cluster(&self) -> bool {
+        self.highlight.is_some() || self.stories.is_some()
+    }
+
+    /// Publication date in ISO 8601 format
+    pub fn published_at(&self) -> Option<String> {
+        iso_date(&self.iso_date, &self.date)
+    }
+}
+
+/// Article within a story cluster
+#[derive(Debug, Deserialize, Clone)]
+pub struct NewsArticle {
+    pub position: Option<u32>,
+    pub title: Option<String>,
+    pub source: Option<NewsSource>,
+    pub link: Option<String>,
+    pub thumbnail: Option<String>,
+    pub date: Option<String>,
+    pub iso_date: Option<String>,
+    pub story_token: Option<String>,
+}
+
+impl NewsArticle {
+    /// Publication date in ISO 8601 format
+    pub fn published_at(&self) -> Option<String> {
+        iso_date(&self.iso_date, &self.date)
+    }
+}
+
+/// Publisher of a news article
+#[derive(Debug, Deserialize, Clone)]
+pub struct NewsSource {
+    pub name: String,
+    pub icon: Option<String>,
+    pub authors: Option<Vec<String>>,
+}
+
+/// Topic, section or top stories link
+#[derive(Debug, Deserialize, Clone)]
+pub struct NewsMenuLink {
+    pub title: Option<String>,
+    pub topic_token: Option<String>,
+    pub section_token: Option<String>,
+    pub serpapi_link: Option<String>,
+}
+
+fn iso_date(iso_date: &Option<String>, date: &Option<String>) -> Option<String> {
+    iso_date
+        .clone()
+        .or_else(|| date.as_deref().and_then(parse::parse_news_date))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_news_query_validation() {
+        assert!(GoogleNewsQuery::topic("T").section("S").validate().is_ok());
+        assert!(GoogleNewsQuery::new("rust")
+            .section("S")
+            .validate()
+            .is_err());
+        assert!(GoogleNewsQuery::new("rust")
+            .sort_by_date()
+            .validate()
+            .is_err());
+        assert_eq!(
+            GoogleNewsQuery::story("ST")
+                .sort_by_date()
+                .params()
+                .unwrap(),
+            vec![
+                ("story_token".to_string(), "ST".to_string()),
+                ("so".to_string(), "1".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_news_results_parsing() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "news_results": [
+                {
+                    "position": 1,
+                    "title": "Rust 2.0 announced",
+                    "source": {"name": "The Register", "authors": ["A. Writer"]},
+                    "link": "https://example.com/rust",
+                    "date": "07/17/2024, 07:00 AM, +0000 UTC"
+                },
+                {
+                    "position": 2,
+                    "highlight": {"title": "Lead", "source": {"name": "BBC"}, "date": "07/16/2024, 01:15 PM, +0000 UTC"},
+                    "stories": [{"title": "Follow-up", "source": {"name": "CNN"}}],
+                    "story_token": "STORY"
+                }
+            ],
+            "menu_links": [{"title": "Technology", "topic_token": "TECH"}]
+        }"#;
+        let results: GoogleNewsResults = serde_json::from_str(json).unwrap();
+        let news = results.news_results.unwrap();
+        assert!(!news[0].is_cluster());
+        assert_eq!(
+            news[0].published_at().as_deref(),
+            Some("2024-07-17T07:00:00Z")
+        );
+        assert!(news[1].is_cluster());
+        assert_eq!(
+            news[1]
+                .highlight
+                .as_ref()
+                .unwrap()
+                .published_at()
+                .as_deref(),
+            Some("2024-07-16T13:15:00Z")
+        );
+    }
+}
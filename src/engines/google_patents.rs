@@ -0,0 +1,465 @@
+#![allow(missing_docs)]
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::pin::Pin;
+
+use crate::{
+    client::SerpClient,
+    engines::{has_next_page, EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    parse,
+    response::{SearchMetadata, SerpapiPagination},
+    streaming::StreamConfig,
+};
+
+/// Date a patent range filter applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatentDateField {
+    Priority,
+    Filing,
+    Publication,
+}
+
+impl PatentDateField {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Priority => "priority",
+            Self::Filing => "filing",
+            Self::Publication => "publication",
+        }
+    }
+}
+
+/// Legal status filter (status parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PatentStatus {
+    Grant,
+    Application,
+}
+
+/// Sort order for patents (sort parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PatentSort {
+    /// Newest first
+    New,
+    /// Oldest first
+    Old,
+}
+
+/// Query for the `google_patents` engine.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use serp_sdk::{SerpClient, StreamConfig};
+/// use serp_sdk::engines::google_patents::{GooglePatentsQuery, PatentDateField, PatentStatus};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = GooglePatentsQuery::new("(lidar) (autonomous vehicle)")
+///     .assignee("Waymo")
+///     .date_range(PatentDateField::Priority, Some("2018-01-01"), None)?
+///     .status(PatentStatus::Grant)
+///     .limit(50)?;
+///
+/// let mut patents = client.patents_stream(query, StreamConfig::new().max_pages(2));
+/// while let Some(patent) = patents.next().await {
+///     let patent = patent?;
+///     println!("{} {}", patent.publication_number.unwrap_or_default(), patent.title);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct GooglePatentsQuery {
+    q: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inventor: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    assignee: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<PatentStatus>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<PatentSort>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl GooglePatentsQuery {
+    /// Create a patent search; separate several terms with ';'
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            q: query.into(),
+            inventor: None,
+            assignee: None,
+            before: None,
+            after: None,
+            country: None,
+            language: None,
+            status: None,
+            sort: None,
+            num: None,
+            page: None,
+        }
+    }
+
+    /// Filter by inventor; separate several inventors with ','
+    pub fn inventor(mut self, inventor: impl Into<String>) -> Self {
+        self.inventor = Some(inventor.into());
+        self
+    }
+
+    /// Filter by assignee; separate several assignees with ','
+    pub fn assignee(mut self, assignee: impl Into<String>) -> Self {
+        self.assignee = Some(assignee.into());
+        self
+    }
+
+    /// Only include patents whose date falls in a range of "YYYY-MM-DD" dates
+    pub fn date_range(
+        mut self,
+        field: PatentDateField,
+        after: Option<&str>,
+        before: Option<&str>,
+    ) -> SerpResult<Self> {
+        let encode = |date: &str| -> SerpResult<String> {
+            if !parse::is_iso_date(date) {
+                return Err(SerpError::InvalidParameter(format!(
+                    "patent dates must be formatted as YYYY-MM-DD, got {}",
+                    date
+                )));
+            }
+            Ok(format!("{}:{}", field.as_str(), date.replace('-', "")))
+        };
+        if let (Some(after), Some(before)) = (after, before) {
+            if after > before {
+                return Err(SerpError::InvalidParameter(
+                    "start date must not be after end date".to_string(),
+                ));
+            }
+        }
+        self.after = after.map(encode).transpose()?;
+        self.before = before.map(encode).transpose()?;
+        Ok(self)
+    }
+
+    /// Filter by patent office country codes (e.g. "US,WO")
+    pub fn country(mut self, country: impl Into<String>) -> Self {
+        self.country = Some(country.into());
+        self
+    }
+
+    /// Filter by document language (e.g. "ENGLISH,GERMAN")
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Filter by legal status
+    pub fn status(mut self, status: PatentStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Set the sort order (relevance by default)
+    pub fn sort_by(mut self, sort: PatentSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Set the number of results per page (10-100)
+    pub fn limit(mut self, num: u32) -> SerpResult<Self> {
+        if !(10..=100).contains(&num) {
+            return Err(SerpError::InvalidParameter(
+                "limit must be between 10 and 100".to_string(),
+            ));
+        }
+        self.num = Some(num);
+        Ok(self)
+    }
+
+    /// Set the one-based page number (page parameter)
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+impl EngineQuery for GooglePatentsQuery {
+    type Response = GooglePatentsResults;
+
+    fn engine(&self) -> &str {
+        "google_patents"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        if self.page == Some(0) {
+            return Err(SerpError::InvalidParameter(
+                "patent pages start at 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn next_page(&self, response: &GooglePatentsResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        has_next.then(|| {
+            let page = self.page.unwrap_or(1) + 1;
+            self.clone().page(page)
+        })
+    }
+}
+
+/// Query for the `google_patents_details` engine.
+#[derive(Debug, Clone, Serialize)]
+pub struct GooglePatentDetailsQuery {
+    patent_id: String,
+}
+
+impl GooglePatentDetailsQuery {
+    /// Create a details query for a `patent_id` (e.g. "patent/US11734097B1/en")
+    pub fn new(patent_id: impl Into<String>) -> Self {
+        Self {
+            patent_id: patent_id.into(),
+        }
+    }
+}
+
+impl EngineQuery for GooglePatentDetailsQuery {
+    type Response = GooglePatentDetailsResults;
+
+    fn engine(&self) -> &str {
+        "google_patents_details"
+    }
+}
+
+impl SerpClient {
+    /// Stream individual Google Patents results across pages
+    ///
+    /// Pages are requested with an increasing `page` until SerpAPI stops
+    /// returning a next page or `config.max_pages` is reached.
+    pub fn patents_stream(
+        &self,
+        query: GooglePatentsQuery,
+        config: StreamConfig,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<PatentResult>> + Send + '_>> {
+        self.engine_items_stream(query, config, |page| {
+            page.organic_results.unwrap_or_default()
+        })
+    }
+}
+
+/// Results of a `google_patents` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GooglePatentsResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub total_results: Option<u64>,
+    pub organic_results: Option<Vec<PatentResult>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Patent search result
+#[derive(Debug, Deserialize, Clone)]
+pub struct PatentResult {
+    pub position: Option<u32>,
+    pub rank: Option<u32>,
+    /// Identifier for [`GooglePatentDetailsQuery`]
+    pub patent_id: Option<String>,
+    pub patent_link: Option<String>,
+    pub serpapi_link: Option<String>,
+    pub title: String,
+    pub snippet: Option<String>,
+    pub priority_date: Option<String>,
+    pub filing_date: Option<String>,
+    pub grant_date: Option<String>,
+    pub publication_date: Option<String>,
+    pub inventor: Option<String>,
+    pub assignee: Option<String>,
+    pub publication_number: Option<String>,
+    pub language: Option<String>,
+    pub thumbnail: Option<String>,
+    pub pdf: Option<String>,
+    pub figures: Option<Vec<PatentFigure>>,
+    /// Status per country, e.g. `{"US": "ACTIVE"}`
+    pub country_status: Option<HashMap<String, String>>,
+}
+
+/// Patent drawing
+#[derive(Debug, Deserialize, Clone)]
+pub struct PatentFigure {
+    pub thumbnail: Option<String>,
+    pub full: Option<String>,
+}
+
+/// Results of a `google_patents_details` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GooglePatentDetailsResults {
+    pub search_metadata: SearchMetadata,
+    pub title: String,
+    #[serde(rename = "type")]
+    pub patent_type: Option<String>,
+    pub pdf: Option<String>,
+    pub publication_number: Option<String>,
+    pub country: Option<String>,
+    pub inventors: Option<Vec<PatentInventor>>,
+    pub assignees: Option<Vec<String>>,
+    pub priority_date: Option<String>,
+    pub filing_date: Option<String>,
+    pub publication_date: Option<String>,
+    #[serde(rename = "abstract")]
+    pub abstract_text: Option<String>,
+    pub description_link: Option<String>,
+    pub claims: Option<Vec<String>>,
+    pub images: Option<Vec<String>>,
+    pub classifications: Option<Vec<PatentClassification>>,
+    pub patent_citations: Option<PatentCitations>,
+    pub cited_by: Option<PatentCitations>,
+    pub similar_documents: Option<Vec<PatentCitation>>,
+    pub events: Option<Vec<PatentEvent>>,
+}
+
+/// Inventor of a patent
+#[derive(Debug, Deserialize, Clone)]
+pub struct PatentInventor {
+    pub name: String,
+    pub link: Option<String>,
+    pub serpapi_link: Option<String>,
+}
+
+/// CPC classification of a patent
+#[derive(Debug, Deserialize, Clone)]
+pub struct PatentClassification {
+    pub code: String,
+    pub description: Option<String>,
+    pub leaf: Option<bool>,
+    pub first_code: Option<bool>,
+}
+
+/// Citations split by whether they come from the patent or its family
+#[derive(Debug, Deserialize, Clone)]
+pub struct PatentCitations {
+    pub original: Option<Vec<PatentCitation>>,
+    pub family_to_family: Option<Vec<PatentCitation>>,
+}
+
+/// Cited, citing or similar document
+#[derive(Debug, Deserialize, Clone)]
+pub struct PatentCitation {
+    pub publication_number: Option<String>,
+    pub patent_id: Option<String>,
+    pub serpapi_link: Option<String>,
+    pub title: Option<String>,
+    pub priority_date: Option<String>,
+    pub publication_date: Option<String>,
+    pub assignee_original: Option<String>,
+    pub examiner_cited: Option<bool>,
+}
+
+/// Entry of a patent's legal timeline
+#[derive(Debug, Deserialize, Clone)]
+pub struct PatentEvent {
+    pub date: Option<String>,
+    pub title: Option<String>,
+    #[serde(rename = "type")]
+    pub event_type: Option<String>,
+    pub critical: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_patents_query_params() {
+        let query = GooglePatentsQuery::new("lidar")
+            .inventor("Jane Doe")
+            .date_range(
+                PatentDateField::Filing,
+                Some("2018-01-01"),
+                Some("2020-12-31"),
+            )
+            .unwrap()
+            .status(PatentStatus::Grant)
+            .page(2);
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("q".to_string(), "lidar".to_string()),
+                ("inventor".to_string(), "Jane Doe".to_string()),
+                ("before".to_string(), "filing:20201231".to_string()),
+                ("after".to_string(), "filing:20180101".to_string()),
+                ("status".to_string(), "GRANT".to_string()),
+                ("page".to_string(), "2".to_string()),
+            ]
+        );
+        assert!(GooglePatentsQuery::new("lidar")
+            .date_range(PatentDateField::Priority, Some("2018/01/01"), None)
+            .is_err());
+        assert!(GooglePatentsQuery::new("lidar").limit(5).is_err());
+    }
+
+    #[test]
+    fn test_patents_results_and_details() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "organic_results": [{
+                "position": 1,
+                "patent_id": "patent/US11734097B1/en",
+                "title": "Lidar system",
+                "priority_date": "2018-03-01",
+                "publication_number": "US11734097B1",
+                "pdf": "https://patentimages.storage.googleapis.com/x.pdf",
+                "figures": [{"thumbnail": "t.png", "full": "f.png"}],
+                "country_status": {"US": "ACTIVE"}
+            }],
+            "serpapi_pagination": {"next": "https://serpapi.com/search.json?page=2"}
+        }"#;
+        let results: GooglePatentsResults = serde_json::from_str(json).unwrap();
+        let patent = &results.organic_results.as_ref().unwrap()[0];
+        assert_eq!(patent.country_status.as_ref().unwrap()["US"], "ACTIVE");
+        let next = GooglePatentsQuery::new("lidar")
+            .next_page(&results)
+            .unwrap();
+        assert_eq!(next.page, Some(2));
+
+        let json = r#"{
+            "search_metadata": {"id": "def"},
+            "title": "Lidar system",
+            "abstract": "A lidar system...",
+            "claims": ["1. A lidar system comprising..."],
+            "inventors": [{"name": "Jane Doe"}],
+            "patent_citations": {"original": [{"publication_number": "US9000000B2", "examiner_cited": true}]}
+        }"#;
+        let details: GooglePatentDetailsResults = serde_json::from_str(json).unwrap();
+        assert_eq!(details.claims.unwrap().len(), 1);
+        let cited = &details.patent_citations.unwrap().original.unwrap()[0];
+        assert_eq!(cited.examiner_cited, Some(true));
+    }
+}
@@ -0,0 +1,539 @@
+#![allow(missing_docs)]
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+
+use crate::{
+    client::SerpClient,
+    engines::{google_maps::OwnerResponse, EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    response::{SearchMetadata, SerpapiPagination},
+    streaming::StreamConfig,
+};
+
+/// Google Play store section (store parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlayStore {
+    Apps,
+    Games,
+    Movies,
+    Books,
+}
+
+/// Device to browse Google Play for (store_device parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlayDevice {
+    Phone,
+    Tablet,
+    Tv,
+    Chromebook,
+    Watch,
+    Car,
+}
+
+/// Sort order for Google Play reviews (sort_by parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayReviewSort {
+    MostRelevant,
+    Newest,
+    Rating,
+}
+
+/// Query for the `google_play` engine.
+///
+/// Pages are linked through `next_page_token`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::SerpClient;
+/// use serp_sdk::engines::google_play::{GooglePlayQuery, PlayDevice};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = GooglePlayQuery::search("habit tracker")
+///     .country("us")
+///     .device(PlayDevice::Tablet);
+///
+/// let results = client.search_engine(query).await?;
+/// for app in results.items() {
+///     println!("{} {:?}", app.title, app.rating);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct GooglePlayQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    q: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<PlayStore>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    apps_category: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store_device: Option<PlayDevice>,
+
+    #[serde(rename = "gl", skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_page_token: Option<String>,
+}
+
+impl GooglePlayQuery {
+    /// Create a Google Play search
+    pub fn search(query: impl Into<String>) -> Self {
+        Self {
+            q: Some(query.into()),
+            ..Self::browse(PlayStore::Apps)
+        }
+    }
+
+    /// Browse the front page of a store section
+    pub fn browse(store: PlayStore) -> Self {
+        Self {
+            q: None,
+            store: Some(store),
+            apps_category: None,
+            store_device: None,
+            country: None,
+            language: None,
+            next_page_token: None,
+        }
+    }
+
+    /// Restrict apps to a category (apps_category parameter)
+    /// Examples: "HEALTH_AND_FITNESS", "PRODUCTIVITY", "GAME_PUZZLE"
+    pub fn category(mut self, category: impl Into<String>) -> Self {
+        self.apps_category = Some(category.into());
+        self
+    }
+
+    /// Set the device to browse apps for (store_device parameter)
+    pub fn device(mut self, device: PlayDevice) -> Self {
+        self.store_device = Some(device);
+        self
+    }
+
+    /// Set the country (gl parameter)
+    pub fn country(mut self, gl: impl Into<String>) -> Self {
+        self.country = Some(gl.into());
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Continue from a page token returned by a previous response
+    pub fn page_token(mut self, token: impl Into<String>) -> Self {
+        self.next_page_token = Some(token.into());
+        self
+    }
+}
+
+impl EngineQuery for GooglePlayQuery {
+    type Response = GooglePlayResults;
+
+    fn engine(&self) -> &str {
+        "google_play"
+    }
+
+    fn next_page(&self, response: &GooglePlayResults) -> Option<Self> {
+        response
+            .serpapi_pagination
+            .as_ref()
+            .and_then(|p| p.next_page_token.clone())
+            .map(|token| self.clone().page_token(token))
+    }
+}
+
+/// Query for the `google_play_product` engine.
+///
+/// Reviews are only paginated once [`reviews`](Self::reviews) is set; use
+/// [`SerpClient::google_play_reviews_stream`] to iterate over every review.
+#[derive(Debug, Clone, Serialize)]
+pub struct GooglePlayProductQuery {
+    product_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<PlayStore>,
+
+    #[serde(rename = "gl", skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    all_reviews: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort_by: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rating: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    platform: Option<PlayDevice>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_page_token: Option<String>,
+}
+
+impl GooglePlayProductQuery {
+    /// Create a product query for a package name (e.g. "com.google.android.youtube")
+    pub fn new(product_id: impl Into<String>) -> Self {
+        Self {
+            product_id: product_id.into(),
+            store: None,
+            country: None,
+            language: None,
+            all_reviews: None,
+            sort_by: None,
+            rating: None,
+            platform: None,
+            num: None,
+            next_page_token: None,
+        }
+    }
+
+    /// Set the store section the product belongs to (apps by default)
+    pub fn store(mut self, store: PlayStore) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Set the country (gl parameter)
+    pub fn country(mut self, gl: impl Into<String>) -> Self {
+        self.country = Some(gl.into());
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Return all reviews, paginated, instead of product details with a few reviews
+    pub fn reviews(mut self) -> Self {
+        self.all_reviews = Some(true);
+        self
+    }
+
+    /// Set the review sort order
+    pub fn sort_reviews_by(mut self, sort: PlayReviewSort) -> Self {
+        self.sort_by = Some(match sort {
+            PlayReviewSort::MostRelevant => 1,
+            PlayReviewSort::Newest => 2,
+            PlayReviewSort::Rating => 3,
+        });
+        self
+    }
+
+    /// Only return reviews with a given star rating (1-5)
+    pub fn rating(mut self, stars: u8) -> SerpResult<Self> {
+        if !(1..=5).contains(&stars) {
+            return Err(SerpError::InvalidParameter(
+                "rating must be between 1 and 5".to_string(),
+            ));
+        }
+        self.rating = Some(stars);
+        Ok(self)
+    }
+
+    /// Only return reviews written on a device (platform parameter)
+    pub fn platform(mut self, platform: PlayDevice) -> Self {
+        self.platform = Some(platform);
+        self
+    }
+
+    /// Set the number of reviews per page (1-199)
+    pub fn limit(mut self, num: u32) -> SerpResult<Self> {
+        if num == 0 || num > 199 {
+            return Err(SerpError::InvalidParameter(
+                "limit must be between 1 and 199".to_string(),
+            ));
+        }
+        self.num = Some(num);
+        Ok(self)
+    }
+
+    /// Continue from a page token returned by a previous response
+    pub fn page_token(mut self, token: impl Into<String>) -> Self {
+        self.next_page_token = Some(token.into());
+        self
+    }
+}
+
+impl EngineQuery for GooglePlayProductQuery {
+    type Response = GooglePlayProductResults;
+
+    fn engine(&self) -> &str {
+        "google_play_product"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        if self.all_reviews.is_none() && (self.num.is_some() || self.next_page_token.is_some()) {
+            return Err(SerpError::InvalidParameter(
+                "review limit and page tokens require reviews()".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn next_page(&self, response: &GooglePlayProductResults) -> Option<Self> {
+        self.all_reviews?;
+        response
+            .serpapi_pagination
+            .as_ref()
+            .and_then(|p| p.next_page_token.clone())
+            .map(|token| self.clone().page_token(token))
+    }
+}
+
+impl SerpClient {
+    /// Stream individual Google Play reviews across pages
+    ///
+    /// The query is switched to [`reviews`](GooglePlayProductQuery::reviews)
+    /// mode and pages are followed through `next_page_token`.
+    pub fn google_play_reviews_stream(
+        &self,
+        query: GooglePlayProductQuery,
+        config: StreamConfig,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<PlayReview>> + Send + '_>> {
+        self.engine_items_stream(query.reviews(), config, |page| {
+            page.reviews.unwrap_or_default()
+        })
+    }
+}
+
+/// Results of a `google_play` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GooglePlayResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    /// App shown prominently for a search that names it
+    pub app_highlight: Option<PlayItem>,
+    pub organic_results: Option<Vec<PlaySection>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+impl GooglePlayResults {
+    /// Every item across sections, starting with the highlighted app
+    pub fn items(&self) -> impl Iterator<Item = &PlayItem> {
+        self.app_highlight.iter().chain(
+            self.organic_results
+                .iter()
+                .flatten()
+                .flat_map(|section| section.items.iter()),
+        )
+    }
+}
+
+/// Titled row of apps, games, movies or books
+#[derive(Debug, Deserialize, Clone)]
+pub struct PlaySection {
+    pub title: Option<String>,
+    pub section_page_token: Option<String>,
+    #[serde(default)]
+    pub items: Vec<PlayItem>,
+}
+
+/// App, game, movie or book listed on Google Play
+#[derive(Debug, Deserialize, Clone)]
+pub struct PlayItem {
+    pub position: Option<u32>,
+    pub title: String,
+    /// Package name for [`GooglePlayProductQuery`]
+    pub product_id: Option<String>,
+    pub link: Option<String>,
+    pub serpapi_link: Option<String>,
+    pub rating: Option<f32>,
+    pub author: Option<String>,
+    pub category: Option<String>,
+    pub downloads: Option<String>,
+    pub price: Option<String>,
+    pub extracted_price: Option<f64>,
+    pub description: Option<String>,
+    pub thumbnail: Option<String>,
+    pub video: Option<String>,
+}
+
+/// Results of a `google_play_product` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GooglePlayProductResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub product_info: Option<PlayProductInfo>,
+    pub about_this_app: Option<PlayAbout>,
+    /// Number of ratings per star level
+    pub ratings: Option<Vec<PlayRatingCount>>,
+    pub reviews: Option<Vec<PlayReview>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+impl GooglePlayProductResults {
+    /// Number of ratings for 1 to 5 stars, in that order
+    pub fn rating_histogram(&self) -> [u64; 5] {
+        let mut histogram = [0; 5];
+        for bar in self.ratings.iter().flatten() {
+            if (1..=5).contains(&bar.stars) {
+                histogram[bar.stars as usize - 1] = bar.count;
+            }
+        }
+        histogram
+    }
+}
+
+/// Store listing of a product
+#[derive(Debug, Deserialize, Clone)]
+pub struct PlayProductInfo {
+    pub title: String,
+    pub authors: Option<Vec<PlayAuthor>>,
+    pub extensions: Option<Vec<String>>,
+    pub rating: Option<f32>,
+    pub reviews: Option<u64>,
+    /// e.g. "1B+"
+    pub downloads: Option<String>,
+    pub content_rating: Option<PlayContentRating>,
+    pub thumbnail: Option<String>,
+    pub offers: Option<Vec<PlayOffer>>,
+}
+
+/// Developer or author of a product
+#[derive(Debug, Deserialize, Clone)]
+pub struct PlayAuthor {
+    pub name: String,
+    pub link: Option<String>,
+    pub serpapi_link: Option<String>,
+}
+
+/// Content rating of a product
+#[derive(Debug, Deserialize, Clone)]
+pub struct PlayContentRating {
+    pub text: Option<String>,
+    pub thumbnail: Option<String>,
+}
+
+/// Install or purchase option
+#[derive(Debug, Deserialize, Clone)]
+pub struct PlayOffer {
+    pub text: Option<String>,
+    pub price: Option<String>,
+    pub extracted_price: Option<f64>,
+    pub link: Option<String>,
+}
+
+/// "About this app" section
+#[derive(Debug, Deserialize, Clone)]
+pub struct PlayAbout {
+    pub snippet: Option<String>,
+    pub info: Option<EngineParameters>,
+}
+
+/// Bar of the ratings histogram
+#[derive(Debug, Deserialize, Clone)]
+pub struct PlayRatingCount {
+    pub stars: u8,
+    pub count: u64,
+}
+
+/// Google Play review
+#[derive(Debug, Deserialize, Clone)]
+pub struct PlayReview {
+    pub id: Option<String>,
+    /// Reviewer name
+    pub title: Option<String>,
+    pub avatar: Option<String>,
+    pub rating: Option<f32>,
+    pub snippet: Option<String>,
+    pub likes: Option<u32>,
+    pub date: Option<String>,
+    pub iso_date: Option<String>,
+    /// Reply from the developer
+    pub response: Option<OwnerResponse>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_play_query_params() {
+        let query = GooglePlayQuery::search("habit tracker")
+            .device(PlayDevice::Tablet)
+            .country("us");
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("q".to_string(), "habit tracker".to_string()),
+                ("store".to_string(), "apps".to_string()),
+                ("store_device".to_string(), "tablet".to_string()),
+                ("gl".to_string(), "us".to_string()),
+            ]
+        );
+
+        let product = GooglePlayProductQuery::new("com.example.app")
+            .limit(50)
+            .unwrap();
+        assert!(product.validate().is_err());
+        let product = product.reviews().sort_reviews_by(PlayReviewSort::Newest);
+        assert!(product.validate().is_ok());
+        assert!(product
+            .params()
+            .unwrap()
+            .contains(&("sort_by".to_string(), "2".to_string())));
+    }
+
+    #[test]
+    fn test_play_product_parsing_and_next_page() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "product_info": {"title": "Example", "rating": 4.5, "reviews": 1200, "downloads": "1M+"},
+            "ratings": [
+                {"stars": 5, "count": 900},
+                {"stars": 4, "count": 200},
+                {"stars": 1, "count": 100}
+            ],
+            "reviews": [{
+                "id": "r1",
+                "title": "Sam",
+                "rating": 5,
+                "snippet": "Great app",
+                "likes": 3,
+                "iso_date": "2024-05-01T10:00:00Z",
+                "response": {"snippet": "Thanks!", "date": "May 2, 2024"}
+            }],
+            "serpapi_pagination": {"next_page_token": "tok123"}
+        }"#;
+        let results: GooglePlayProductResults = serde_json::from_str(json).unwrap();
+        assert_eq!(results.rating_histogram(), [100, 0, 0, 200, 900]);
+        let review = &results.reviews.as_ref().unwrap()[0];
+        assert_eq!(
+            review.response.as_ref().unwrap().snippet.as_deref(),
+            Some("Thanks!")
+        );
+
+        let query = GooglePlayProductQuery::new("com.example.app");
+        assert!(query.next_page(&results).is_none());
+        let next = query.reviews().next_page(&results).unwrap();
+        assert_eq!(next.next_page_token.as_deref(), Some("tok123"));
+    }
+}
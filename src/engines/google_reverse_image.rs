@@ -0,0 +1,173 @@
+#![allow(missing_docs)]
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+
+use crate::{
+    client::SerpClient,
+    engines::{google_lens::check_image_url, has_next_page, EngineParameters, EngineQuery},
+    error::SerpResult,
+    response::{
+        InlineImage, KnowledgeGraph, OrganicResult, SearchInformation, SearchMetadata,
+        SerpapiPagination,
+    },
+    streaming::StreamConfig,
+};
+
+/// Query for the `google_reverse_image` engine.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use serp_sdk::{SerpClient, StreamConfig};
+/// use serp_sdk::engines::google_reverse_image::GoogleReverseImageQuery;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = GoogleReverseImageQuery::new("https://example.com/photo.jpg");
+///
+/// let mut pages = client.reverse_image_stream(query, StreamConfig::new().max_pages(3));
+/// while let Some(page) = pages.next().await {
+///     println!("found on {}", page?.link);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleReverseImageQuery {
+    image_url: String,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(rename = "gl", skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start: Option<u32>,
+}
+
+impl GoogleReverseImageQuery {
+    /// Create a reverse image search for a publicly reachable image URL
+    pub fn new(image_url: impl Into<String>) -> Self {
+        Self {
+            image_url: image_url.into(),
+            language: None,
+            country: None,
+            start: None,
+        }
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Set the country (gl parameter)
+    pub fn country(mut self, gl: impl Into<String>) -> Self {
+        self.country = Some(gl.into());
+        self
+    }
+
+    /// Set the offset for pagination (start parameter)
+    pub fn offset(mut self, start: u32) -> Self {
+        self.start = Some(start);
+        self
+    }
+}
+
+impl EngineQuery for GoogleReverseImageQuery {
+    type Response = GoogleReverseImageResults;
+
+    fn engine(&self) -> &str {
+        "google_reverse_image"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        check_image_url(&self.image_url)
+    }
+
+    fn next_page(&self, response: &GoogleReverseImageResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        let returned = response.image_results.as_ref().map_or(0, Vec::len) as u32;
+        (has_next && returned > 0).then(|| {
+            let start = self.start.unwrap_or(0) + returned;
+            self.clone().offset(start)
+        })
+    }
+}
+
+impl SerpClient {
+    /// Stream pages that contain the searched image
+    ///
+    /// Pages are requested with an increasing `start` offset until SerpAPI stops
+    /// returning a next page or `config.max_pages` is reached.
+    pub fn reverse_image_stream(
+        &self,
+        query: GoogleReverseImageQuery,
+        config: StreamConfig,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<OrganicResult>> + Send + '_>> {
+        self.engine_items_stream(query, config, |page| page.image_results.unwrap_or_default())
+    }
+}
+
+/// Results of a `google_reverse_image` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleReverseImageResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub search_information: Option<SearchInformation>,
+    pub image_sizes: Option<Vec<ImageSize>>,
+    pub knowledge_graph: Option<KnowledgeGraph>,
+    /// Pages that include the image
+    pub image_results: Option<Vec<OrganicResult>>,
+    /// Visually similar images
+    pub inline_images: Option<Vec<InlineImage>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Other available size of the searched image
+#[derive(Debug, Deserialize, Clone)]
+pub struct ImageSize {
+    pub title: Option<String>,
+    pub link: Option<String>,
+    pub serpapi_link: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reverse_image_results_and_next_page() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "image_results": [
+                {"position": 1, "title": "Photo essay", "link": "https://news.example.com/a"},
+                {"position": 2, "title": "Gallery", "link": "https://blog.example.com/b"}
+            ],
+            "inline_images": [{"source": "https://blog.example.com/b", "thumbnail": "t.jpg"}],
+            "serpapi_pagination": {"next": "https://serpapi.com/search.json?start=2"}
+        }"#;
+        let results: GoogleReverseImageResults = serde_json::from_str(json).unwrap();
+        assert_eq!(results.inline_images.as_ref().unwrap().len(), 1);
+
+        let query = GoogleReverseImageQuery::new("https://example.com/a.jpg").country("us");
+        let next = query.next_page(&results).unwrap();
+        assert_eq!(
+            next.params().unwrap(),
+            vec![
+                (
+                    "image_url".to_string(),
+                    "https://example.com/a.jpg".to_string()
+                ),
+                ("gl".to_string(), "us".to_string()),
+                ("start".to_string(), "2".to_string()),
+            ]
+        );
+    }
+}
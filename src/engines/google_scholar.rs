@@ -0,0 +1,769 @@
+#![allow(missing_docs)]
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::pin::Pin;
+
+use crate::{
+    client::SerpClient,
+    engines::{has_next_page, EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    response::{SearchInformation, SearchMetadata, SerpapiPagination},
+    streaming::StreamConfig,
+};
+
+/// Query for the `google_scholar` engine.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::SerpClient;
+/// use serp_sdk::engines::google_scholar::GoogleScholarQuery;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = GoogleScholarQuery::new("ownership types")
+///     .years(2015, 2024)?
+///     .limit(20)?;
+///
+/// let results = client.search_engine(query).await?;
+/// for article in results.organic_results.unwrap_or_default() {
+///     let cited_by = article.cited_by().and_then(|c| c.total).unwrap_or(0);
+///     println!("{} ({} citations)", article.title, cited_by);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleScholarQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    q: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cites: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cluster: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    as_ylo: Option<u16>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    as_yhi: Option<u16>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scisbd: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    as_sdt: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    as_vis: Option<u8>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lr: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start: Option<u32>,
+}
+
+impl GoogleScholarQuery {
+    fn empty() -> Self {
+        Self {
+            q: None,
+            cites: None,
+            cluster: None,
+            as_ylo: None,
+            as_yhi: None,
+            scisbd: None,
+            as_sdt: None,
+            as_vis: None,
+            language: None,
+            lr: None,
+            num: None,
+            start: None,
+        }
+    }
+
+    /// Search for articles matching a query
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            q: Some(query.into()),
+            ..Self::empty()
+        }
+    }
+
+    /// List articles citing an article (cites_id from `inline_links.cited_by`)
+    pub fn cited_by(cites_id: impl Into<String>) -> Self {
+        Self {
+            cites: Some(cites_id.into()),
+            ..Self::empty()
+        }
+    }
+
+    /// List all versions of an article (cluster_id from `inline_links.versions`)
+    pub fn versions(cluster_id: impl Into<String>) -> Self {
+        Self {
+            cluster: Some(cluster_id.into()),
+            ..Self::empty()
+        }
+    }
+
+    /// Narrow a [`cited_by`](Self::cited_by) listing with a query
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.q = Some(query.into());
+        self
+    }
+
+    /// Only include articles published in or after a year (as_ylo parameter)
+    pub fn from_year(mut self, year: u16) -> Self {
+        self.as_ylo = Some(year);
+        self
+    }
+
+    /// Only include articles published in or before a year (as_yhi parameter)
+    pub fn to_year(mut self, year: u16) -> Self {
+        self.as_yhi = Some(year);
+        self
+    }
+
+    /// Only include articles published within an inclusive year range
+    pub fn years(self, from: u16, to: u16) -> SerpResult<Self> {
+        if from > to {
+            return Err(SerpError::InvalidParameter(
+                "start year must not be after end year".to_string(),
+            ));
+        }
+        Ok(self.from_year(from).to_year(to))
+    }
+
+    /// Sort by date, only including abstracts (scisbd=1) or everything (scisbd=2)
+    pub fn sort_by_date(mut self, include_everything: bool) -> Self {
+        self.scisbd = Some(if include_everything { 2 } else { 1 });
+        self
+    }
+
+    /// Set the search type or filter (as_sdt parameter)
+    /// Examples: "0,5" (exclude patents), "7" (include patents), "4" (case law)
+    pub fn search_type(mut self, as_sdt: impl Into<String>) -> Self {
+        self.as_sdt = Some(as_sdt.into());
+        self
+    }
+
+    /// Exclude citations from the results (as_vis=1)
+    pub fn exclude_citations(mut self) -> Self {
+        self.as_vis = Some(1);
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Restrict results to languages (lr parameter)
+    /// Example: "lang_en|lang_fr"
+    pub fn languages(mut self, lr: impl Into<String>) -> Self {
+        self.lr = Some(lr.into());
+        self
+    }
+
+    /// Set the number of results per page (1-20)
+    pub fn limit(mut self, num: u32) -> SerpResult<Self> {
+        if num == 0 || num > 20 {
+            return Err(SerpError::InvalidParameter(
+                "limit must be between 1 and 20".to_string(),
+            ));
+        }
+        self.num = Some(num);
+        Ok(self)
+    }
+
+    /// Set the offset for pagination (start parameter)
+    pub fn offset(mut self, start: u32) -> Self {
+        self.start = Some(start);
+        self
+    }
+}
+
+impl EngineQuery for GoogleScholarQuery {
+    type Response = GoogleScholarResults;
+
+    fn engine(&self) -> &str {
+        "google_scholar"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        if self.cluster.is_some() && (self.q.is_some() || self.cites.is_some()) {
+            return Err(SerpError::InvalidParameter(
+                "cluster cannot be combined with q or cites".to_string(),
+            ));
+        }
+        if self.q.is_none() && self.cites.is_none() && self.cluster.is_none() {
+            return Err(SerpError::InvalidParameter(
+                "scholar search requires q, cites or cluster".to_string(),
+            ));
+        }
+        if let (Some(from), Some(to)) = (self.as_ylo, self.as_yhi) {
+            if from > to {
+                return Err(SerpError::InvalidParameter(
+                    "start year must not be after end year".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn next_page(&self, response: &GoogleScholarResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        has_next.then(|| {
+            let start = self.start.unwrap_or(0) + self.num.unwrap_or(10);
+            self.clone().offset(start)
+        })
+    }
+}
+
+impl SerpClient {
+    /// Stream individual Google Scholar articles across pages
+    ///
+    /// Pages are requested with an increasing `start` offset until SerpAPI stops
+    /// returning a next page or `config.max_pages` is reached.
+    pub fn scholar_results_stream(
+        &self,
+        query: GoogleScholarQuery,
+        config: StreamConfig,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<ScholarResult>> + Send + '_>> {
+        self.engine_items_stream(query, config, |page| {
+            page.organic_results.unwrap_or_default()
+        })
+    }
+}
+
+/// Query for the `google_scholar_cite` engine.
+///
+/// Returns the formatted citations (MLA, APA, ...) and export links (BibTeX,
+/// EndNote, ...) of a result.
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleScholarCiteQuery {
+    q: String,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+}
+
+impl GoogleScholarCiteQuery {
+    /// Create a citation query for a result ID (`result_id` of an organic result)
+    pub fn new(result_id: impl Into<String>) -> Self {
+        Self {
+            q: result_id.into(),
+            language: None,
+        }
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+}
+
+impl EngineQuery for GoogleScholarCiteQuery {
+    type Response = GoogleScholarCiteResults;
+
+    fn engine(&self) -> &str {
+        "google_scholar_cite"
+    }
+}
+
+/// Sort order for an author's articles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuthorArticleSort {
+    /// Alphabetically by title
+    Title,
+    /// Most recent first
+    PubDate,
+}
+
+/// Query for the `google_scholar_author` engine.
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleScholarAuthorQuery {
+    author_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    view_op: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    citation_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<AuthorArticleSort>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start: Option<u32>,
+}
+
+impl GoogleScholarAuthorQuery {
+    /// Create a query for an author profile (e.g. "LSsXyncAAAAJ")
+    pub fn new(author_id: impl Into<String>) -> Self {
+        Self {
+            author_id: author_id.into(),
+            view_op: None,
+            citation_id: None,
+            sort: None,
+            language: None,
+            num: None,
+            start: None,
+        }
+    }
+
+    /// Show the details of one of the author's articles
+    pub fn citation(mut self, citation_id: impl Into<String>) -> Self {
+        self.view_op = Some("view_citation".to_string());
+        self.citation_id = Some(citation_id.into());
+        self
+    }
+
+    /// List all co-authors
+    pub fn colleagues(mut self) -> Self {
+        self.view_op = Some("list_colleagues".to_string());
+        self
+    }
+
+    /// Set the sort order of articles
+    pub fn sort_by(mut self, sort: AuthorArticleSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Set the number of articles per page (1-100)
+    pub fn limit(mut self, num: u32) -> SerpResult<Self> {
+        if num == 0 || num > 100 {
+            return Err(SerpError::InvalidParameter(
+                "limit must be between 1 and 100".to_string(),
+            ));
+        }
+        self.num = Some(num);
+        Ok(self)
+    }
+
+    /// Set the offset for pagination (start parameter)
+    pub fn offset(mut self, start: u32) -> Self {
+        self.start = Some(start);
+        self
+    }
+}
+
+impl EngineQuery for GoogleScholarAuthorQuery {
+    type Response = GoogleScholarAuthorResults;
+
+    fn engine(&self) -> &str {
+        "google_scholar_author"
+    }
+
+    fn next_page(&self, response: &GoogleScholarAuthorResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        (has_next && self.view_op.is_none()).then(|| {
+            let start = self.start.unwrap_or(0) + self.num.unwrap_or(20);
+            self.clone().offset(start)
+        })
+    }
+}
+
+/// Query for the `google_scholar_profiles` engine.
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleScholarProfilesQuery {
+    mauthors: String,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after_author: Option<String>,
+}
+
+impl GoogleScholarProfilesQuery {
+    /// Search author profiles by name, affiliation or label (e.g. "label:rust")
+    pub fn new(mauthors: impl Into<String>) -> Self {
+        Self {
+            mauthors: mauthors.into(),
+            language: None,
+            after_author: None,
+        }
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Continue after a page token returned by a previous response
+    pub fn page_token(mut self, token: impl Into<String>) -> Self {
+        self.after_author = Some(token.into());
+        self
+    }
+}
+
+impl EngineQuery for GoogleScholarProfilesQuery {
+    type Response = GoogleScholarProfilesResults;
+
+    fn engine(&self) -> &str {
+        "google_scholar_profiles"
+    }
+
+    fn next_page(&self, response: &GoogleScholarProfilesResults) -> Option<Self> {
+        response
+            .pagination
+            .as_ref()
+            .and_then(|p| p.next_page_token.clone())
+            .map(|token| self.clone().page_token(token))
+    }
+}
+
+/// Results of a `google_scholar` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleScholarResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub search_information: Option<SearchInformation>,
+    pub organic_results: Option<Vec<ScholarResult>>,
+    pub profiles: Option<ScholarProfilesBlock>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Scholar article result
+#[derive(Debug, Deserialize, Clone)]
+pub struct ScholarResult {
+    pub position: Option<u32>,
+    pub title: String,
+    pub result_id: Option<String>,
+    #[serde(rename = "type")]
+    pub result_type: Option<String>,
+    pub link: Option<String>,
+    pub snippet: Option<String>,
+    pub publication_info: Option<PublicationInfo>,
+    pub resources: Option<Vec<ScholarResource>>,
+    pub inline_links: Option<ScholarInlineLinks>,
+}
+
+impl ScholarResult {
+    /// Citation count and link, if the article has been cited
+    pub fn cited_by(&self) -> Option<&CitedBy> {
+        self.inline_links.as_ref()?.cited_by.as_ref()
+    }
+
+    /// Link to the first PDF resource of the article
+    pub fn pdf_link(&self) -> Option<&str> {
+        self.resources
+            .as_ref()?
+            .iter()
+            .find(|r| r.file_format.as_deref() == Some("PDF"))
+            .and_then(|r| r.link.as_deref())
+    }
+}
+
+/// Authors and venue of a publication
+#[derive(Debug, Deserialize, Clone)]
+pub struct PublicationInfo {
+    pub summary: Option<String>,
+    pub authors: Option<Vec<ScholarAuthorRef>>,
+}
+
+/// Reference to an author profile
+#[derive(Debug, Deserialize, Clone)]
+pub struct ScholarAuthorRef {
+    pub name: String,
+    pub link: Option<String>,
+    pub serpapi_scholar_link: Option<String>,
+    pub author_id: Option<String>,
+}
+
+/// Full-text resource (PDF, HTML) of an article
+#[derive(Debug, Deserialize, Clone)]
+pub struct ScholarResource {
+    pub title: Option<String>,
+    pub file_format: Option<String>,
+    pub link: Option<String>,
+}
+
+/// Follow-up links of an article
+#[derive(Debug, Deserialize, Clone)]
+pub struct ScholarInlineLinks {
+    pub serpapi_cite_link: Option<String>,
+    pub cited_by: Option<CitedBy>,
+    pub versions: Option<ScholarVersions>,
+    pub related_pages_link: Option<String>,
+    pub serpapi_related_pages_link: Option<String>,
+    pub cached_page_link: Option<String>,
+}
+
+/// Citation count of an article
+#[derive(Debug, Deserialize, Clone)]
+pub struct CitedBy {
+    pub total: Option<u64>,
+    pub link: Option<String>,
+    pub cites_id: Option<String>,
+    pub serpapi_scholar_link: Option<String>,
+}
+
+/// Other versions of an article
+#[derive(Debug, Deserialize, Clone)]
+pub struct ScholarVersions {
+    pub total: Option<u64>,
+    pub link: Option<String>,
+    pub cluster_id: Option<String>,
+    pub serpapi_scholar_link: Option<String>,
+}
+
+/// Author profiles shown above scholar results
+#[derive(Debug, Deserialize, Clone)]
+pub struct ScholarProfilesBlock {
+    pub title: Option<String>,
+    pub link: Option<String>,
+    pub authors: Option<Vec<ScholarAuthorRef>>,
+}
+
+/// Results of a `google_scholar_cite` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleScholarCiteResults {
+    pub search_metadata: SearchMetadata,
+    pub citations: Option<Vec<Citation>>,
+    pub links: Option<Vec<CitationLink>>,
+}
+
+impl GoogleScholarCiteResults {
+    /// Get a formatted citation by style name (e.g. "MLA", "APA", "Chicago")
+    pub fn citation(&self, style: &str) -> Option<&str> {
+        self.citations
+            .as_ref()?
+            .iter()
+            .find(|c| c.title.eq_ignore_ascii_case(style))
+            .map(|c| c.snippet.as_str())
+    }
+
+    /// Get the export link for a format (e.g. "BibTeX", "EndNote", "RefMan")
+    pub fn export_link(&self, format: &str) -> Option<&str> {
+        self.links
+            .as_ref()?
+            .iter()
+            .find(|l| l.name.eq_ignore_ascii_case(format))
+            .map(|l| l.link.as_str())
+    }
+}
+
+/// Citation formatted in one style
+#[derive(Debug, Deserialize, Clone)]
+pub struct Citation {
+    /// Style name, e.g. "MLA"
+    pub title: String,
+    pub snippet: String,
+}
+
+/// Citation export link
+#[derive(Debug, Deserialize, Clone)]
+pub struct CitationLink {
+    /// Format name, e.g. "BibTeX"
+    pub name: String,
+    pub link: String,
+}
+
+/// Results of a `google_scholar_author` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleScholarAuthorResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub author: Option<ScholarAuthor>,
+    pub articles: Option<Vec<AuthorArticle>>,
+    pub cited_by: Option<AuthorCitedBy>,
+    pub co_authors: Option<Vec<ScholarProfile>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Author profile header
+#[derive(Debug, Deserialize, Clone)]
+pub struct ScholarAuthor {
+    pub name: String,
+    pub affiliations: Option<String>,
+    pub email: Option<String>,
+    pub website: Option<String>,
+    pub interests: Option<Vec<ScholarInterest>>,
+    pub thumbnail: Option<String>,
+}
+
+/// Research interest of an author
+#[derive(Debug, Deserialize, Clone)]
+pub struct ScholarInterest {
+    pub title: String,
+    pub link: Option<String>,
+    pub serpapi_link: Option<String>,
+}
+
+/// Article listed on an author profile
+#[derive(Debug, Deserialize, Clone)]
+pub struct AuthorArticle {
+    pub title: String,
+    pub link: Option<String>,
+    pub citation_id: Option<String>,
+    pub authors: Option<String>,
+    pub publication: Option<String>,
+    pub cited_by: Option<ArticleCitedBy>,
+    pub year: Option<String>,
+}
+
+/// Citation count of an article on an author profile
+#[derive(Debug, Deserialize, Clone)]
+pub struct ArticleCitedBy {
+    pub value: Option<u64>,
+    pub link: Option<String>,
+    pub serpapi_link: Option<String>,
+    pub cites_id: Option<String>,
+}
+
+/// Citation metrics of an author
+#[derive(Debug, Deserialize, Clone)]
+pub struct AuthorCitedBy {
+    /// Rows such as `{"citations": {"all": 120, "since_2019": 80}}`
+    pub table: Option<Vec<HashMap<String, HashMap<String, u64>>>>,
+    pub graph: Option<Vec<CitationsPerYear>>,
+}
+
+/// Citations received in one year
+#[derive(Debug, Deserialize, Clone)]
+pub struct CitationsPerYear {
+    pub year: u32,
+    pub citations: u64,
+}
+
+/// Results of a `google_scholar_profiles` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleScholarProfilesResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub profiles: Option<Vec<ScholarProfile>>,
+    pub pagination: Option<ProfilesPagination>,
+}
+
+/// Author profile search result
+#[derive(Debug, Deserialize, Clone)]
+pub struct ScholarProfile {
+    pub name: String,
+    pub link: Option<String>,
+    pub serpapi_link: Option<String>,
+    pub author_id: Option<String>,
+    pub affiliations: Option<String>,
+    pub email: Option<String>,
+    pub cited_by: Option<u64>,
+    pub interests: Option<Vec<ScholarInterest>>,
+    pub thumbnail: Option<String>,
+}
+
+/// Token-based pagination of profile searches
+#[derive(Debug, Deserialize, Clone)]
+pub struct ProfilesPagination {
+    pub next: Option<String>,
+    pub next_page_token: Option<String>,
+    pub previous: Option<String>,
+    pub previous_page_token: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scholar_query_validation() {
+        assert!(GoogleScholarQuery::new("rust").validate().is_ok());
+        assert!(GoogleScholarQuery::new("rust").years(2024, 2015).is_err());
+        assert!(GoogleScholarQuery::versions("123")
+            .query("rust")
+            .validate()
+            .is_err());
+
+        let params = GoogleScholarQuery::cited_by("456")
+            .from_year(2020)
+            .search_type("0,5")
+            .params()
+            .unwrap();
+        assert_eq!(
+            params,
+            vec![
+                ("cites".to_string(), "456".to_string()),
+                ("as_ylo".to_string(), "2020".to_string()),
+                ("as_sdt".to_string(), "0,5".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scholar_results_parsing() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "organic_results": [{
+                "position": 0,
+                "title": "Ownership types",
+                "result_id": "RID",
+                "publication_info": {
+                    "summary": "D Clarke - 1998",
+                    "authors": [{"name": "D Clarke", "author_id": "A1"}]
+                },
+                "resources": [{"title": "acm.org", "file_format": "PDF", "link": "https://x/y.pdf"}],
+                "inline_links": {
+                    "cited_by": {"total": 1234, "cites_id": "C1"},
+                    "versions": {"total": 5, "cluster_id": "K1"}
+                }
+            }],
+            "serpapi_pagination": {"next": "https://serpapi.com/search.json?start=10"}
+        }"#;
+        let results: GoogleScholarResults = serde_json::from_str(json).unwrap();
+        let article = &results.organic_results.as_ref().unwrap()[0];
+        assert_eq!(article.cited_by().unwrap().total, Some(1234));
+        assert_eq!(article.pdf_link(), Some("https://x/y.pdf"));
+
+        let next = GoogleScholarQuery::new("ownership")
+            .limit(20)
+            .unwrap()
+            .next_page(&results)
+            .unwrap();
+        assert_eq!(next.start, Some(20));
+    }
+
+    #[test]
+    fn test_cite_results() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "citations": [{"title": "MLA", "snippet": "Clarke, David. Ownership types."}],
+            "links": [{"name": "BibTeX", "link": "https://scholar.googleusercontent.com/bib"}]
+        }"#;
+        let results: GoogleScholarCiteResults = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            results.citation("mla"),
+            Some("Clarke, David. Ownership types.")
+        );
+        assert_eq!(
+            results.export_link("bibtex"),
+            Some("https://scholar.googleusercontent.com/bib")
+        );
+    }
+}
@@ -0,0 +1,531 @@
+#![allow(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{
+    engines::{check_price_range, has_next_page, EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    response::{Price, SearchMetadata, SerpapiPagination, ShoppingResult},
+};
+
+/// Sort order for shopping results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShoppingSort {
+    PriceLowToHigh,
+    PriceHighToLow,
+}
+
+/// Item condition filter for shopping results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProductCondition {
+    New,
+    Refurbished,
+    Used,
+}
+
+impl ProductCondition {
+    fn tbs(self) -> &'static str {
+        match self {
+            Self::New => "mr:1,new:1",
+            Self::Refurbished => "mr:1,new:2",
+            Self::Used => "mr:1,new:3",
+        }
+    }
+}
+
+/// Query for the `google_shopping` engine.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::SerpClient;
+/// use serp_sdk::engines::google_shopping::{GoogleShoppingQuery, ShoppingSort};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = GoogleShoppingQuery::new("mechanical keyboard")
+///     .price_range(Some(50.0), Some(150.0))?
+///     .sort_by(ShoppingSort::PriceLowToHigh);
+///
+/// let results = client.search_engine(query).await?;
+/// for product in results.shopping_results.unwrap_or_default() {
+///     if let Some(price) = product.parsed_price() {
+///         println!("{}: {} {:?}", product.title, price.amount, price.currency);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleShoppingQuery {
+    q: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    google_domain: Option<String>,
+
+    #[serde(rename = "gl", skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_price: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_price: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort_by: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    free_shipping: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    on_sale: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tbs: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shoprs: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start: Option<u32>,
+}
+
+impl GoogleShoppingQuery {
+    /// Create a shopping search query
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            q: query.into(),
+            location: None,
+            google_domain: None,
+            country: None,
+            language: None,
+            min_price: None,
+            max_price: None,
+            sort_by: None,
+            free_shipping: None,
+            on_sale: None,
+            tbs: None,
+            shoprs: None,
+            start: None,
+        }
+    }
+
+    /// Set the location to search from
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    /// Set the Google domain (e.g. "google.co.uk")
+    pub fn domain(mut self, google_domain: impl Into<String>) -> Self {
+        self.google_domain = Some(google_domain.into());
+        self
+    }
+
+    /// Set the country (gl parameter)
+    pub fn country(mut self, gl: impl Into<String>) -> Self {
+        self.country = Some(gl.into());
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Only include products priced within a range (min_price/max_price parameters)
+    pub fn price_range(mut self, min: Option<f64>, max: Option<f64>) -> SerpResult<Self> {
+        check_price_range(min, max)?;
+        self.min_price = min;
+        self.max_price = max;
+        Ok(self)
+    }
+
+    /// Set the sort order (sort_by parameter)
+    pub fn sort_by(mut self, sort: ShoppingSort) -> Self {
+        self.sort_by = Some(match sort {
+            ShoppingSort::PriceLowToHigh => 1,
+            ShoppingSort::PriceHighToLow => 2,
+        });
+        self
+    }
+
+    /// Only include items in a given condition
+    pub fn condition(mut self, condition: ProductCondition) -> Self {
+        self.tbs = Some(condition.tbs().to_string());
+        self
+    }
+
+    /// Only include products with free shipping
+    pub fn free_shipping(mut self) -> Self {
+        self.free_shipping = Some(true);
+        self
+    }
+
+    /// Only include products on sale
+    pub fn on_sale(mut self) -> Self {
+        self.on_sale = Some(true);
+        self
+    }
+
+    /// Apply a filter token from the `filters` of a previous response (shoprs parameter)
+    pub fn filter(mut self, shoprs: impl Into<String>) -> Self {
+        self.shoprs = Some(shoprs.into());
+        self
+    }
+
+    /// Set the offset for pagination (start parameter)
+    pub fn offset(mut self, start: u32) -> Self {
+        self.start = Some(start);
+        self
+    }
+}
+
+impl EngineQuery for GoogleShoppingQuery {
+    type Response = GoogleShoppingResults;
+
+    fn engine(&self) -> &str {
+        "google_shopping"
+    }
+
+    fn next_page(&self, response: &GoogleShoppingResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        let returned = response.shopping_results.as_ref().map_or(0, Vec::len) as u32;
+        (has_next && returned > 0).then(|| {
+            let start = self.start.unwrap_or(0) + returned;
+            self.clone().offset(start)
+        })
+    }
+}
+
+/// Section of a product page to fetch with [`GoogleProductQuery`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProductView {
+    /// Product overview (default)
+    Overview,
+    /// All seller offers
+    Offers,
+    /// Technical specifications
+    Specs,
+    /// User reviews
+    Reviews,
+}
+
+/// Query for the `google_product` engine.
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleProductQuery {
+    product_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offers: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    specs: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reviews: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    google_domain: Option<String>,
+
+    #[serde(rename = "gl", skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start: Option<u32>,
+}
+
+impl GoogleProductQuery {
+    /// Create a query for a `product_id` from a shopping result
+    pub fn new(product_id: impl Into<String>) -> Self {
+        Self {
+            product_id: product_id.into(),
+            offers: None,
+            specs: None,
+            reviews: None,
+            location: None,
+            google_domain: None,
+            country: None,
+            language: None,
+            start: None,
+        }
+    }
+
+    /// Select the section of the product page to fetch
+    pub fn view(mut self, view: ProductView) -> Self {
+        self.offers = (view == ProductView::Offers).then_some(1);
+        self.specs = (view == ProductView::Specs).then_some(1);
+        self.reviews = (view == ProductView::Reviews).then_some(1);
+        self
+    }
+
+    /// Set the location to search from
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    /// Set the Google domain (e.g. "google.co.uk")
+    pub fn domain(mut self, google_domain: impl Into<String>) -> Self {
+        self.google_domain = Some(google_domain.into());
+        self
+    }
+
+    /// Set the country (gl parameter)
+    pub fn country(mut self, gl: impl Into<String>) -> Self {
+        self.country = Some(gl.into());
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Set the offset of offers or reviews (start parameter)
+    pub fn offset(mut self, start: u32) -> Self {
+        self.start = Some(start);
+        self
+    }
+}
+
+impl EngineQuery for GoogleProductQuery {
+    type Response = GoogleProductResults;
+
+    fn engine(&self) -> &str {
+        "google_product"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        if self.start.is_some() && self.offers.is_none() && self.reviews.is_none() {
+            return Err(SerpError::InvalidParameter(
+                "offset is only available for offers and reviews".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Results of a `google_shopping` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleShoppingResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub shopping_results: Option<Vec<ShoppingResult>>,
+    pub inline_shopping_results: Option<Vec<ShoppingResult>>,
+    pub filters: Option<Vec<ShoppingFilter>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Filter group offered for refining a shopping search
+#[derive(Debug, Deserialize, Clone)]
+pub struct ShoppingFilter {
+    #[serde(rename = "type")]
+    pub filter_type: String,
+    pub options: Vec<ShoppingFilterOption>,
+}
+
+/// Value of a shopping filter
+#[derive(Debug, Deserialize, Clone)]
+pub struct ShoppingFilterOption {
+    pub text: String,
+    /// Value to pass to [`GoogleShoppingQuery::filter`]
+    pub shoprs: Option<String>,
+    pub serpapi_link: Option<String>,
+}
+
+/// Results of a `google_product` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleProductResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub product_results: Option<ProductDetails>,
+    pub sellers_results: Option<SellersResults>,
+    /// Specifications grouped by section, e.g. `{"Display": {"Size": "13.3 in"}}`
+    pub specs_results: Option<HashMap<String, HashMap<String, String>>>,
+    pub reviews_results: Option<ProductReviewsResults>,
+    pub related_products: Option<Vec<ShoppingResult>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Product overview
+#[derive(Debug, Deserialize, Clone)]
+pub struct ProductDetails {
+    pub product_id: Option<String>,
+    pub title: String,
+    pub prices: Option<Vec<String>>,
+    pub typical_prices: Option<TypicalPrices>,
+    pub conditions: Option<Vec<String>>,
+    pub rating: Option<f64>,
+    pub reviews: Option<u32>,
+    pub extensions: Option<Vec<String>>,
+    pub description: Option<String>,
+    pub media: Option<Vec<ProductMedia>>,
+    pub highlights: Option<Vec<String>>,
+}
+
+impl ProductDetails {
+    /// Displayed prices as amounts and currencies
+    pub fn parsed_prices(&self) -> Vec<Price> {
+        self.prices
+            .iter()
+            .flatten()
+            .filter_map(|p| Price::parse(p))
+            .collect()
+    }
+}
+
+/// Typical price range of a product
+#[derive(Debug, Deserialize, Clone)]
+pub struct TypicalPrices {
+    pub low: Option<String>,
+    pub high: Option<String>,
+    pub shown_price: Option<String>,
+}
+
+/// Image or video of a product
+#[derive(Debug, Deserialize, Clone)]
+pub struct ProductMedia {
+    #[serde(rename = "type")]
+    pub media_type: String,
+    pub link: String,
+}
+
+/// Seller offers of a product
+#[derive(Debug, Deserialize, Clone)]
+pub struct SellersResults {
+    pub online_sellers: Option<Vec<SellerOffer>>,
+    pub local_sellers: Option<Vec<SellerOffer>>,
+}
+
+/// Offer of a single seller
+#[derive(Debug, Deserialize, Clone)]
+pub struct SellerOffer {
+    pub position: Option<u32>,
+    pub name: String,
+    pub link: Option<String>,
+    pub details_and_offers: Option<Vec<String>>,
+    pub base_price: Option<String>,
+    pub additional_price: Option<AdditionalPrice>,
+    pub total_price: Option<String>,
+}
+
+impl SellerOffer {
+    /// Item price before shipping and tax
+    pub fn parsed_base_price(&self) -> Option<Price> {
+        self.base_price.as_deref().and_then(Price::parse)
+    }
+
+    /// Price including shipping and tax
+    pub fn parsed_total_price(&self) -> Option<Price> {
+        self.total_price.as_deref().and_then(Price::parse)
+    }
+}
+
+/// Shipping and tax added to an offer
+#[derive(Debug, Deserialize, Clone)]
+pub struct AdditionalPrice {
+    pub shipping: Option<String>,
+    pub tax: Option<String>,
+}
+
+/// Ratings and reviews of a product
+#[derive(Debug, Deserialize, Clone)]
+pub struct ProductReviewsResults {
+    pub ratings: Option<Vec<StarCount>>,
+    pub reviews: Option<Vec<ProductReview>>,
+}
+
+/// Number of reviews with a given star rating
+#[derive(Debug, Deserialize, Clone)]
+pub struct StarCount {
+    pub stars: u8,
+    pub amount: u64,
+}
+
+/// Product review
+#[derive(Debug, Deserialize, Clone)]
+pub struct ProductReview {
+    pub position: Option<u32>,
+    pub title: Option<String>,
+    pub date: Option<String>,
+    pub rating: Option<f64>,
+    pub source: Option<String>,
+    pub content: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shopping_query_params() {
+        let query = GoogleShoppingQuery::new("keyboard")
+            .price_range(Some(50.0), Some(150.5))
+            .unwrap()
+            .sort_by(ShoppingSort::PriceHighToLow)
+            .condition(ProductCondition::Used)
+            .filter("TOKEN");
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("q".to_string(), "keyboard".to_string()),
+                ("min_price".to_string(), "50.0".to_string()),
+                ("max_price".to_string(), "150.5".to_string()),
+                ("sort_by".to_string(), "2".to_string()),
+                ("tbs".to_string(), "mr:1,new:3".to_string()),
+                ("shoprs".to_string(), "TOKEN".to_string()),
+            ]
+        );
+        assert!(GoogleShoppingQuery::new("keyboard")
+            .price_range(Some(10.0), Some(5.0))
+            .is_err());
+        assert!(GoogleProductQuery::new("1").offset(10).validate().is_err());
+    }
+
+    #[test]
+    fn test_product_results_prices() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "product_results": {"title": "Keyboard", "prices": ["$129.99", "$149.00"], "rating": 4.6, "reviews": 812},
+            "sellers_results": {"online_sellers": [{
+                "name": "Best Buy",
+                "base_price": "$129.99",
+                "additional_price": {"shipping": "Free", "tax": "$10.73"},
+                "total_price": "$140.72"
+            }]},
+            "specs_results": {"General": {"Layout": "ANSI"}},
+            "reviews_results": {"ratings": [{"stars": 5, "amount": 600}]}
+        }"#;
+        let results: GoogleProductResults = serde_json::from_str(json).unwrap();
+        let product = results.product_results.unwrap();
+        assert_eq!(product.parsed_prices().len(), 2);
+
+        let offer = &results.sellers_results.unwrap().online_sellers.unwrap()[0];
+        assert_eq!(
+            offer.parsed_total_price(),
+            Some(Price {
+                amount: 140.72,
+                currency: Some("USD".to_string())
+            })
+        );
+        assert_eq!(results.specs_results.unwrap()["General"]["Layout"], "ANSI");
+    }
+}
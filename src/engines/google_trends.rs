@@ -0,0 +1,596 @@
+#![allow(missing_docs)]
+
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::{
+    engines::{EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    parse,
+    response::SearchMetadata,
+};
+
+/// Maximum number of queries Google Trends can compare at once
+const MAX_COMPARED_QUERIES: usize = 5;
+
+/// Kind of Google Trends data to fetch (data_type parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum TrendsDataType {
+    /// Interest over time
+    #[serde(rename = "TIMESERIES")]
+    TimeSeries,
+    /// Compared interest by region, for several queries
+    #[serde(rename = "GEO_MAP")]
+    GeoMap,
+    /// Interest by region, for a single query
+    #[serde(rename = "GEO_MAP_0")]
+    GeoMapSingle,
+    /// Related queries, for a single query
+    #[serde(rename = "RELATED_QUERIES")]
+    RelatedQueries,
+    /// Related topics, for a single query
+    #[serde(rename = "RELATED_TOPICS")]
+    RelatedTopics,
+}
+
+/// Granularity of interest by region (region parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TrendsRegion {
+    Country,
+    Region,
+    Dma,
+    City,
+}
+
+/// Google property to measure interest on (gprop parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrendsProperty {
+    Images,
+    News,
+    #[serde(rename = "froogle")]
+    Shopping,
+    #[serde(rename = "youtube")]
+    YouTube,
+}
+
+/// Query for the `google_trends` engine.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::SerpClient;
+/// use serp_sdk::engines::google_trends::GoogleTrendsQuery;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = GoogleTrendsQuery::new("rust")
+///     .compare_with("go")
+///     .geo("US")
+///     .date("today 12-m");
+///
+/// let results = client.search_engine(query).await?;
+/// if let Some(interest) = results.interest_over_time {
+///     for series in interest.series() {
+///         println!("{}: {} points", series.query, series.points.len());
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleTrendsQuery {
+    #[serde(rename = "q", serialize_with = "serialize_queries")]
+    queries: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data_type: Option<TrendsDataType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    geo: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<TrendsRegion>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tz: Option<i32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cat: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gprop: Option<TrendsProperty>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+}
+
+fn serialize_queries<S: Serializer>(queries: &[String], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&queries.join(","))
+}
+
+impl GoogleTrendsQuery {
+    /// Create a query for the interest in a search term
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            queries: vec![query.into()],
+            data_type: None,
+            geo: None,
+            region: None,
+            date: None,
+            tz: None,
+            cat: None,
+            gprop: None,
+            language: None,
+        }
+    }
+
+    /// Add a search term to compare against (up to five terms in total)
+    pub fn compare_with(mut self, query: impl Into<String>) -> Self {
+        self.queries.push(query.into());
+        self
+    }
+
+    /// Set the kind of data to fetch (defaults to TIMESERIES)
+    pub fn data_type(mut self, data_type: TrendsDataType) -> Self {
+        self.data_type = Some(data_type);
+        self
+    }
+
+    /// Set the location (geo parameter)
+    /// Examples: "US", "US-CA", "GB"; worldwide when unset
+    pub fn geo(mut self, geo: impl Into<String>) -> Self {
+        self.geo = Some(geo.into());
+        self
+    }
+
+    /// Set the granularity of interest by region
+    pub fn region(mut self, region: TrendsRegion) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Set the time range (date parameter)
+    /// Common values: "now 1-H", "now 7-d", "today 1-m", "today 12-m", "today 5-y", "all"
+    pub fn date(mut self, date: impl Into<String>) -> Self {
+        self.date = Some(date.into());
+        self
+    }
+
+    /// Set a custom time range with dates formatted as "YYYY-MM-DD"
+    pub fn date_range(self, from: &str, to: &str) -> SerpResult<Self> {
+        if !parse::is_iso_date(from) || !parse::is_iso_date(to) {
+            return Err(SerpError::InvalidParameter(
+                "date range must be formatted as YYYY-MM-DD".to_string(),
+            ));
+        }
+        if from > to {
+            return Err(SerpError::InvalidParameter(
+                "start date must not be after end date".to_string(),
+            ));
+        }
+        Ok(self.date(format!("{} {}", from, to)))
+    }
+
+    /// Set the time zone as an offset from UTC in minutes (tz parameter)
+    /// Example: 420 for PDT
+    pub fn time_zone(mut self, offset_minutes: i32) -> Self {
+        self.tz = Some(offset_minutes);
+        self
+    }
+
+    /// Restrict to a category (cat parameter)
+    pub fn category(mut self, cat: u32) -> Self {
+        self.cat = Some(cat);
+        self
+    }
+
+    /// Measure interest on a Google property other than web search
+    pub fn property(mut self, gprop: TrendsProperty) -> Self {
+        self.gprop = Some(gprop);
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Get the compared search terms
+    pub fn queries(&self) -> &[String] {
+        &self.queries
+    }
+}
+
+impl EngineQuery for GoogleTrendsQuery {
+    type Response = GoogleTrendsResults;
+
+    fn engine(&self) -> &str {
+        "google_trends"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        if self.queries.len() > MAX_COMPARED_QUERIES {
+            return Err(SerpError::InvalidParameter(format!(
+                "google trends compares at most {} queries",
+                MAX_COMPARED_QUERIES
+            )));
+        }
+        if self
+            .queries
+            .iter()
+            .any(|q| q.trim().is_empty() || q.contains(','))
+        {
+            return Err(SerpError::InvalidParameter(
+                "trends queries must be non-empty and cannot contain commas".to_string(),
+            ));
+        }
+        let single_query_only = matches!(
+            self.data_type,
+            Some(TrendsDataType::GeoMapSingle)
+                | Some(TrendsDataType::RelatedQueries)
+                | Some(TrendsDataType::RelatedTopics)
+        );
+        if single_query_only && self.queries.len() > 1 {
+            return Err(SerpError::InvalidParameter(
+                "this data_type accepts a single query".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Query for the `google_trends_trending_now` engine.
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleTrendsTrendingNowQuery {
+    geo: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hours: Option<u16>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    category_id: Option<u32>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+}
+
+impl GoogleTrendsTrendingNowQuery {
+    /// Create a query for searches trending in a location (e.g. "US")
+    pub fn new(geo: impl Into<String>) -> Self {
+        Self {
+            geo: geo.into(),
+            hours: None,
+            category_id: None,
+            language: None,
+        }
+    }
+
+    /// Set the look-back window in hours (4, 24, 48 or 168)
+    pub fn hours(mut self, hours: u16) -> SerpResult<Self> {
+        if ![4, 24, 48, 168].contains(&hours) {
+            return Err(SerpError::InvalidParameter(
+                "hours must be one of 4, 24, 48 or 168".to_string(),
+            ));
+        }
+        self.hours = Some(hours);
+        Ok(self)
+    }
+
+    /// Restrict to a category (category_id parameter)
+    pub fn category(mut self, category_id: u32) -> Self {
+        self.category_id = Some(category_id);
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+}
+
+impl EngineQuery for GoogleTrendsTrendingNowQuery {
+    type Response = GoogleTrendsTrendingNowResults;
+
+    fn engine(&self) -> &str {
+        "google_trends_trending_now"
+    }
+}
+
+/// Results of a `google_trends` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleTrendsResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub interest_over_time: Option<InterestOverTime>,
+    pub compared_breakdown_by_region: Option<Vec<RegionBreakdown>>,
+    pub interest_by_region: Option<Vec<RegionInterest>>,
+    pub related_queries: Option<RelatedQueries>,
+    pub related_topics: Option<RelatedTopics>,
+}
+
+/// Interest over time (TIMESERIES)
+#[derive(Debug, Deserialize, Clone)]
+pub struct InterestOverTime {
+    pub timeline_data: Vec<TimelineEntry>,
+    pub averages: Option<Vec<TrendValue>>,
+}
+
+impl InterestOverTime {
+    /// Split the timeline into one series per query, in query order
+    ///
+    /// Entries without a timestamp and values without a number are skipped.
+    pub fn series(&self) -> Vec<TimeSeries> {
+        let mut series: Vec<TimeSeries> = Vec::new();
+        for entry in &self.timeline_data {
+            let Some(timestamp) = entry.timestamp else {
+                continue;
+            };
+            for value in &entry.values {
+                let Some(extracted) = value.extracted_value else {
+                    continue;
+                };
+                let query = value.query.clone().unwrap_or_default();
+                let position = match series.iter().position(|s| s.query == query) {
+                    Some(position) => position,
+                    None => {
+                        series.push(TimeSeries {
+                            query,
+                            points: Vec::new(),
+                        });
+                        series.len() - 1
+                    }
+                };
+                series[position].points.push(TrendPoint {
+                    timestamp,
+                    value: extracted,
+                    partial: entry.partial_data.unwrap_or(false),
+                });
+            }
+        }
+        series
+    }
+}
+
+/// One point of the interest timeline, with a value per query
+#[derive(Debug, Deserialize, Clone)]
+pub struct TimelineEntry {
+    pub date: String,
+    /// Unix timestamp (seconds) of the start of the period
+    #[serde(default, deserialize_with = "parse::deserialize_integer")]
+    pub timestamp: Option<u64>,
+    pub values: Vec<TrendValue>,
+    pub partial_data: Option<bool>,
+}
+
+/// Interest in a query, scaled 0-100
+#[derive(Debug, Deserialize, Clone)]
+pub struct TrendValue {
+    pub query: Option<String>,
+    /// Displayed value, e.g. "<1"
+    #[serde(default, deserialize_with = "deserialize_display_value")]
+    pub value: Option<String>,
+    #[serde(default, deserialize_with = "parse::deserialize_count")]
+    pub extracted_value: Option<u64>,
+}
+
+/// Interest in a single query over time
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeSeries {
+    pub query: String,
+    pub points: Vec<TrendPoint>,
+}
+
+/// A timestamped interest value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrendPoint {
+    /// Unix timestamp (seconds)
+    pub timestamp: u64,
+    /// Interest, scaled 0-100
+    pub value: u64,
+    /// Whether the period is still in progress
+    pub partial: bool,
+}
+
+/// Compared interest in one region (GEO_MAP)
+#[derive(Debug, Deserialize, Clone)]
+pub struct RegionBreakdown {
+    pub geo: Option<String>,
+    pub location: String,
+    pub max_value_index: Option<usize>,
+    pub values: Vec<TrendValue>,
+}
+
+/// Interest in one region (GEO_MAP_0)
+#[derive(Debug, Deserialize, Clone)]
+pub struct RegionInterest {
+    pub geo: Option<String>,
+    pub location: String,
+    #[serde(default, deserialize_with = "deserialize_display_value")]
+    pub value: Option<String>,
+    #[serde(default, deserialize_with = "parse::deserialize_count")]
+    pub extracted_value: Option<u64>,
+}
+
+/// Related queries, split into rising and top
+#[derive(Debug, Deserialize, Clone)]
+pub struct RelatedQueries {
+    pub rising: Option<Vec<RelatedQuery>>,
+    pub top: Option<Vec<RelatedQuery>>,
+}
+
+/// Related query
+#[derive(Debug, Deserialize, Clone)]
+pub struct RelatedQuery {
+    pub query: String,
+    /// Displayed value, e.g. "+450%" or "Breakout"
+    #[serde(default, deserialize_with = "deserialize_display_value")]
+    pub value: Option<String>,
+    #[serde(default, deserialize_with = "parse::deserialize_count")]
+    pub extracted_value: Option<u64>,
+    pub link: Option<String>,
+    pub serpapi_link: Option<String>,
+}
+
+/// Related topics, split into rising and top
+#[derive(Debug, Deserialize, Clone)]
+pub struct RelatedTopics {
+    pub rising: Option<Vec<RelatedTopic>>,
+    pub top: Option<Vec<RelatedTopic>>,
+}
+
+/// Related topic
+#[derive(Debug, Deserialize, Clone)]
+pub struct RelatedTopic {
+    pub topic: TrendsTopic,
+    #[serde(default, deserialize_with = "deserialize_display_value")]
+    pub value: Option<String>,
+    #[serde(default, deserialize_with = "parse::deserialize_count")]
+    pub extracted_value: Option<u64>,
+    pub link: Option<String>,
+    pub serpapi_link: Option<String>,
+}
+
+/// Knowledge graph topic
+#[derive(Debug, Deserialize, Clone)]
+pub struct TrendsTopic {
+    /// Topic ID, e.g. "/m/0dgw9r"
+    pub value: String,
+    pub title: String,
+    #[serde(rename = "type")]
+    pub topic_type: Option<String>,
+}
+
+/// Results of a `google_trends_trending_now` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct GoogleTrendsTrendingNowResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub trending_searches: Option<Vec<TrendingSearch>>,
+}
+
+/// Trending search
+#[derive(Debug, Deserialize, Clone)]
+pub struct TrendingSearch {
+    pub query: String,
+    pub start_timestamp: Option<u64>,
+    pub end_timestamp: Option<u64>,
+    pub active: Option<bool>,
+    pub search_volume: Option<u64>,
+    pub increase_percentage: Option<u64>,
+    pub categories: Option<Vec<TrendingCategory>>,
+    pub trend_breakdown: Option<Vec<String>>,
+    pub serpapi_google_trends_link: Option<String>,
+    pub news_page_token: Option<String>,
+}
+
+/// Category of a trending search
+#[derive(Debug, Deserialize, Clone)]
+pub struct TrendingCategory {
+    pub id: u32,
+    pub name: String,
+}
+
+/// Deserialize a displayed value that SerpAPI sends either as text or as a number
+fn deserialize_display_value<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(
+        match Option::<serde_json::Value>::deserialize(deserializer)? {
+            Some(serde_json::Value::String(s)) => Some(s),
+            Some(serde_json::Value::Number(n)) => Some(n.to_string()),
+            _ => None,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trends_query_params() {
+        let query = GoogleTrendsQuery::new("rust")
+            .compare_with("go")
+            .data_type(TrendsDataType::GeoMap)
+            .date_range("2024-01-01", "2024-06-30")
+            .unwrap();
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("q".to_string(), "rust,go".to_string()),
+                ("data_type".to_string(), "GEO_MAP".to_string()),
+                ("date".to_string(), "2024-01-01 2024-06-30".to_string()),
+            ]
+        );
+        assert!(query.validate().is_ok());
+    }
+
+    #[test]
+    fn test_trends_query_validation() {
+        let too_many = (0..6).fold(GoogleTrendsQuery::new("a"), |q, i| {
+            q.compare_with(format!("q{}", i))
+        });
+        assert!(too_many.validate().is_err());
+        assert!(GoogleTrendsQuery::new("a")
+            .date_range("2024-06-30", "2024-01-01")
+            .is_err());
+        assert!(GoogleTrendsQuery::new("a")
+            .date_range("last month", "2024-01-01")
+            .is_err());
+        assert!(GoogleTrendsQuery::new("a,b").validate().is_err());
+        assert!(GoogleTrendsQuery::new("rust")
+            .compare_with("go")
+            .data_type(TrendsDataType::RelatedQueries)
+            .validate()
+            .is_err());
+        assert!(GoogleTrendsTrendingNowQuery::new("US").hours(12).is_err());
+    }
+
+    #[test]
+    fn test_interest_over_time_series() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "interest_over_time": {
+                "timeline_data": [
+                    {"date": "Jan 1, 2024", "timestamp": "1704067200", "values": [
+                        {"query": "rust", "value": "75", "extracted_value": 75},
+                        {"query": "go", "value": "<1", "extracted_value": 0}
+                    ]},
+                    {"date": "Jan 8, 2024", "timestamp": "1704672000", "partial_data": true, "values": [
+                        {"query": "rust", "value": "80", "extracted_value": 80},
+                        {"query": "go", "value": "3", "extracted_value": 3}
+                    ]}
+                ],
+                "averages": [{"query": "rust", "value": 77}]
+            }
+        }"#;
+        let results: GoogleTrendsResults = serde_json::from_str(json).unwrap();
+        let series = results.interest_over_time.unwrap().series();
+        assert_eq!(series.len(), 2);
+        assert_eq!(series[0].query, "rust");
+        assert_eq!(
+            series[0].points,
+            vec![
+                TrendPoint {
+                    timestamp: 1_704_067_200,
+                    value: 75,
+                    partial: false
+                },
+                TrendPoint {
+                    timestamp: 1_704_672_000,
+                    value: 80,
+                    partial: true
+                },
+            ]
+        );
+        assert_eq!(series[1].points[0].value, 0);
+    }
+}
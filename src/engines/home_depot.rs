@@ -0,0 +1,268 @@
+#![allow(missing_docs)]
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+
+use crate::{
+    client::SerpClient,
+    engines::{check_price_range, has_next_page, EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    response::{ListingSummary, Price, ProductListing, SearchMetadata, SerpapiPagination},
+    streaming::StreamConfig,
+};
+
+/// Sort order for Home Depot results (hd_sort parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HomeDepotSort {
+    BestMatch,
+    TopSellers,
+    PriceLowToHigh,
+    PriceHighToLow,
+    TopRated,
+}
+
+/// Query for the `home_depot` engine.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use serp_sdk::{SerpClient, StreamConfig};
+/// use serp_sdk::engines::home_depot::{HomeDepotQuery, HomeDepotSort};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = HomeDepotQuery::new("cordless drill")
+///     .store("2414")
+///     .sort_by(HomeDepotSort::TopRated)
+///     .limit(48)?;
+///
+/// let mut products = client.home_depot_stream(query, StreamConfig::new().max_pages(2));
+/// while let Some(product) = products.next().await {
+///     let product = product?;
+///     println!("{} {:?}", product.title, product.price);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct HomeDepotQuery {
+    q: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hd_sort: Option<HomeDepotSort>,
+
+    #[serde(rename = "lowerbound", skip_serializing_if = "Option::is_none")]
+    min_price: Option<f64>,
+
+    #[serde(rename = "upperbound", skip_serializing_if = "Option::is_none")]
+    max_price: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delivery_zip: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ps: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl HomeDepotQuery {
+    /// Create a Home Depot search
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            q: query.into(),
+            hd_sort: None,
+            min_price: None,
+            max_price: None,
+            store_id: None,
+            delivery_zip: None,
+            ps: None,
+            page: None,
+        }
+    }
+
+    /// Set the sort order (hd_sort parameter)
+    pub fn sort_by(mut self, sort: HomeDepotSort) -> Self {
+        self.hd_sort = Some(sort);
+        self
+    }
+
+    /// Only include products priced within a range (lowerbound/upperbound parameters)
+    pub fn price_range(mut self, min: Option<f64>, max: Option<f64>) -> SerpResult<Self> {
+        check_price_range(min, max)?;
+        self.min_price = min;
+        self.max_price = max;
+        Ok(self)
+    }
+
+    /// Show availability and prices for a store (store_id parameter)
+    pub fn store(mut self, store_id: impl Into<String>) -> Self {
+        self.store_id = Some(store_id.into());
+        self
+    }
+
+    /// Set the ZIP code to deliver to (delivery_zip parameter)
+    pub fn delivery_zip(mut self, zip: impl Into<String>) -> Self {
+        self.delivery_zip = Some(zip.into());
+        self
+    }
+
+    /// Set the number of products per page (ps parameter, 1-48)
+    pub fn limit(mut self, ps: u32) -> SerpResult<Self> {
+        if ps == 0 || ps > 48 {
+            return Err(SerpError::InvalidParameter(
+                "limit must be between 1 and 48".to_string(),
+            ));
+        }
+        self.ps = Some(ps);
+        Ok(self)
+    }
+
+    /// Set the one-based page number (page parameter)
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+impl EngineQuery for HomeDepotQuery {
+    type Response = HomeDepotResults;
+
+    fn engine(&self) -> &str {
+        "home_depot"
+    }
+
+    fn next_page(&self, response: &HomeDepotResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        has_next.then(|| {
+            let page = self.page.unwrap_or(1) + 1;
+            self.clone().page(page)
+        })
+    }
+}
+
+impl SerpClient {
+    /// Stream individual Home Depot products across pages
+    pub fn home_depot_stream(
+        &self,
+        query: HomeDepotQuery,
+        config: StreamConfig,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<HomeDepotProduct>> + Send + '_>> {
+        self.engine_items_stream(query, config, |page| page.products.unwrap_or_default())
+    }
+}
+
+/// Results of a `home_depot` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct HomeDepotResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub products: Option<Vec<HomeDepotProduct>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Home Depot product listing
+#[derive(Debug, Deserialize, Clone)]
+pub struct HomeDepotProduct {
+    pub position: Option<u32>,
+    pub product_id: Option<String>,
+    pub title: String,
+    pub link: Option<String>,
+    pub serpapi_link: Option<String>,
+    pub model_number: Option<String>,
+    pub brand: Option<String>,
+    pub rating: Option<f32>,
+    pub reviews: Option<u64>,
+    /// Price in US dollars
+    pub price: Option<f64>,
+    pub price_was: Option<f64>,
+    pub price_saving: Option<f64>,
+    pub percentage_off: Option<f64>,
+    /// e.g. "each" or "sq. ft."
+    pub unit: Option<String>,
+}
+
+impl ProductListing for HomeDepotProduct {
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn link(&self) -> Option<&str> {
+        self.link.as_deref()
+    }
+
+    fn summary(&self) -> ListingSummary {
+        let usd = |amount: f64| Price {
+            amount,
+            currency: Some("USD".to_string()),
+        };
+        ListingSummary {
+            price: self.price.map(usd),
+            old_price: self.price_was.map(usd),
+            rating: self.rating,
+            reviews: self.reviews,
+            seller: Some("The Home Depot".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_home_depot_query_params() {
+        let query = HomeDepotQuery::new("cordless drill")
+            .sort_by(HomeDepotSort::PriceLowToHigh)
+            .price_range(Some(50.0), Some(150.0))
+            .unwrap()
+            .limit(24)
+            .unwrap();
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("q".to_string(), "cordless drill".to_string()),
+                ("hd_sort".to_string(), "price_low_to_high".to_string()),
+                ("lowerbound".to_string(), "50.0".to_string()),
+                ("upperbound".to_string(), "150.0".to_string()),
+                ("ps".to_string(), "24".to_string()),
+            ]
+        );
+        assert!(HomeDepotQuery::new("drill")
+            .price_range(Some(100.0), Some(10.0))
+            .is_err());
+    }
+
+    #[test]
+    fn test_home_depot_results_and_summary() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "products": [{
+                "position": 1,
+                "product_id": "314693508",
+                "title": "20V MAX Cordless Drill",
+                "brand": "DEWALT",
+                "rating": 4.7,
+                "reviews": 6210,
+                "price": 99.0,
+                "price_was": 129.0
+            }],
+            "serpapi_pagination": {"next": "https://serpapi.com/search.json?page=2"}
+        }"#;
+        let results: HomeDepotResults = serde_json::from_str(json).unwrap();
+        let summary = results.products.as_ref().unwrap()[0].summary();
+        assert_eq!(summary.price.unwrap().amount, 99.0);
+        assert_eq!(summary.old_price.unwrap().amount, 129.0);
+
+        let next = HomeDepotQuery::new("drill").next_page(&results).unwrap();
+        assert_eq!(next.page, Some(2));
+    }
+}
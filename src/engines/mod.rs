@@ -0,0 +1,175 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::error::{SerpError, SerpResult};
+use crate::response::SerpapiPagination;
+
+/// Amazon product search.
+pub mod amazon;
+
+/// Apple App Store search and app reviews.
+pub mod apple_app_store;
+
+/// Baidu search with script and page size settings.
+pub mod baidu;
+
+/// Bing web, news, images and shopping search.
+pub mod bing;
+
+/// DuckDuckGo search with region, date and safe search filters.
+pub mod duckduckgo;
+
+/// eBay listing search with condition, price and format filters.
+pub mod ebay;
+
+/// Google Autocomplete suggestions and keyword expansion.
+pub mod google_autocomplete;
+
+/// Google Events search with date and online filters.
+pub mod google_events;
+
+/// Google Finance quotes, price graphs, key stats and financials, and market overviews.
+pub mod google_finance;
+
+/// Google Flights fares, return legs, booking options and price insights.
+pub mod google_flights;
+
+/// Google Hotels search with stay, guest and price filters, and property details.
+pub mod google_hotels;
+
+/// Google Images search with image filters and related content.
+pub mod google_images;
+
+/// Google Jobs search and job listing details.
+pub mod google_jobs;
+
+/// Google Lens visual, exact and product matches for an image.
+pub mod google_lens;
+
+/// Google Local Services providers with badges, ratings and service areas.
+pub mod google_local_services;
+
+/// Google Maps place search, place details, reviews and photos.
+pub mod google_maps;
+
+/// Google News search, topic and publication browsing, and full story coverage.
+pub mod google_news;
+
+/// Google Patents search with date, status and party filters, and patent details.
+pub mod google_patents;
+
+/// Google Play store search, product details, ratings and reviews.
+pub mod google_play;
+
+/// Google reverse image search for pages and similar images.
+pub mod google_reverse_image;
+
+/// Google Scholar articles, citations, author profiles and profile search.
+pub mod google_scholar;
+
+/// Google Shopping search with price filters, and product offers, specs and reviews.
+pub mod google_shopping;
+
+/// Google Trends interest over time, by region, related searches and trending now.
+pub mod google_trends;
+
+/// Home Depot product search with store and price filters.
+pub mod home_depot;
+
+/// Naver search across its web, news, image and video tabs.
+pub mod naver;
+
+/// Tripadvisor place search and place reviews.
+pub mod tripadvisor;
+
+/// Walmart product search and product pages.
+pub mod walmart;
+
+/// Yahoo search with language and country filters.
+pub mod yahoo;
+
+/// Yandex search with region, language and domain settings.
+pub mod yandex;
+
+/// Yelp business search and business reviews.
+pub mod yelp;
+
+/// YouTube search with typed filters and video, channel and playlist results.
+pub mod youtube;
+
+/// Parameters echoed back by SerpAPI for a dedicated engine search.
+pub type EngineParameters = HashMap<String, serde_json::Value>;
+
+/// A typed query for one of SerpAPI's dedicated search engines.
+///
+/// Implementors describe the `engine` they target, the parameters they send and
+/// the response type SerpAPI returns for them. Queries are executed with
+/// [`SerpClient::search_engine`](crate::SerpClient::search_engine) and paginated
+/// with [`SerpClient::engine_stream`](crate::SerpClient::engine_stream).
+pub trait EngineQuery: Serialize + Clone + Send + Sync {
+    /// Typed response returned by the engine
+    type Response: DeserializeOwned + Send;
+
+    /// The SerpAPI `engine` parameter, e.g. "google_maps"
+    fn engine(&self) -> &str;
+
+    /// Request parameters, excluding `engine` and `api_key`
+    ///
+    /// The default implementation serializes the query with `serde_urlencoded`.
+    fn params(&self) -> SerpResult<Vec<(String, String)>> {
+        let encoded = serde_urlencoded::to_string(self)?;
+        Ok(url::form_urlencoded::parse(encoded.as_bytes())
+            .into_owned()
+            .collect())
+    }
+
+    /// Check parameter combinations that SerpAPI would reject
+    fn validate(&self) -> SerpResult<()> {
+        Ok(())
+    }
+
+    /// Build the query for the page following `response`, if there is one
+    fn next_page(&self, _response: &Self::Response) -> Option<Self> {
+        None
+    }
+}
+
+/// Get a string parameter echoed back in `search_parameters`.
+pub(crate) fn echoed_param<'a>(params: &'a Option<EngineParameters>, key: &str) -> Option<&'a str> {
+    params.as_ref()?.get(key)?.as_str()
+}
+
+/// Whether SerpAPI reported a page after the current one.
+pub(crate) fn has_next_page(pagination: &Option<SerpapiPagination>) -> bool {
+    pagination.as_ref().is_some_and(|p| p.next.is_some())
+}
+
+/// Check a min/max price filter before it is sent.
+pub(crate) fn check_price_range(min: Option<f64>, max: Option<f64>) -> SerpResult<()> {
+    if min.into_iter().chain(max).any(|price| price < 0.0) {
+        return Err(SerpError::InvalidParameter(
+            "prices cannot be negative".to_string(),
+        ));
+    }
+    if let (Some(min), Some(max)) = (min, max) {
+        if min > max {
+            return Err(SerpError::InvalidParameter(
+                "min_price must not exceed max_price".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Validate a query and encode it with its engine and API key.
+pub(crate) fn to_query_string<Q: EngineQuery>(query: &Q, api_key: &str) -> SerpResult<String> {
+    query.validate()?;
+
+    let mut params = vec![("engine".to_string(), query.engine().to_string())];
+    params.extend(query.params()?);
+
+    let mut encoded = serde_urlencoded::to_string(params)?;
+    encoded.push_str(&format!("&api_key={}", api_key));
+    Ok(encoded)
+}
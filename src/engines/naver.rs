@@ -0,0 +1,129 @@
+#![allow(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    engines::{has_next_page, EngineParameters, EngineQuery},
+    response::{ImageResult, NewsResult, OrganicResult, SearchMetadata, SerpapiPagination},
+};
+
+/// Naver search tab (where parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NaverTab {
+    /// Integrated results page
+    Nexearch,
+    Web,
+    News,
+    Image,
+    Video,
+}
+
+/// Query for the `naver` engine.
+///
+/// Pages are reached with a one-based `page` number.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::SerpClient;
+/// use serp_sdk::engines::naver::{NaverQuery, NaverTab};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = NaverQuery::new("커피").tab(NaverTab::News);
+///
+/// let results = client.search_engine(query).await?;
+/// for article in results.news_results.unwrap_or_default() {
+///     println!("{}", article.title);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct NaverQuery {
+    query: String,
+
+    #[serde(rename = "where", skip_serializing_if = "Option::is_none")]
+    tab: Option<NaverTab>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl NaverQuery {
+    /// Create a Naver search
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            tab: None,
+            page: None,
+        }
+    }
+
+    /// Set the search tab (where parameter)
+    pub fn tab(mut self, tab: NaverTab) -> Self {
+        self.tab = Some(tab);
+        self
+    }
+
+    /// Set the one-based page number (page parameter)
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+impl EngineQuery for NaverQuery {
+    type Response = NaverResults;
+
+    fn engine(&self) -> &str {
+        "naver"
+    }
+
+    fn next_page(&self, response: &NaverResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        has_next.then(|| {
+            let page = self.page.unwrap_or(1) + 1;
+            self.clone().page(page)
+        })
+    }
+}
+
+/// Results of a `naver` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct NaverResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub organic_results: Option<Vec<OrganicResult>>,
+    pub news_results: Option<Vec<NewsResult>>,
+    pub image_results: Option<Vec<ImageResult>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_naver_params_and_next_page() {
+        let query = NaverQuery::new("coffee").tab(NaverTab::News);
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("query".to_string(), "coffee".to_string()),
+                ("where".to_string(), "news".to_string()),
+            ]
+        );
+
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "news_results": [{"position": 1, "title": "Coffee prices rise", "link": "https://news.example.kr/1", "source": "Example"}],
+            "serpapi_pagination": {"current": 1, "next": "https://serpapi.com/search.json?page=2"}
+        }"#;
+        let results: NaverResults = serde_json::from_str(json).unwrap();
+        assert_eq!(results.news_results.as_ref().unwrap().len(), 1);
+        assert_eq!(query.next_page(&results).unwrap().page, Some(2));
+    }
+}
@@ -0,0 +1,344 @@
+#![allow(missing_docs)]
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+
+use crate::{
+    client::SerpClient,
+    engines::{google_maps::OwnerResponse, has_next_page, EngineParameters, EngineQuery},
+    error::SerpResult,
+    response::{SearchMetadata, SerpapiPagination},
+    streaming::StreamConfig,
+};
+
+/// Kind of place to search for (ssrc parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum TripadvisorCategory {
+    #[serde(rename = "a")]
+    All,
+    #[serde(rename = "r")]
+    Restaurants,
+    #[serde(rename = "A")]
+    ThingsToDo,
+    #[serde(rename = "h")]
+    Hotels,
+    #[serde(rename = "g")]
+    Destinations,
+    #[serde(rename = "v")]
+    VacationRentals,
+    #[serde(rename = "f")]
+    Forums,
+}
+
+/// Query for the `tripadvisor` engine.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::SerpClient;
+/// use serp_sdk::engines::tripadvisor::{TripadvisorCategory, TripadvisorQuery};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = TripadvisorQuery::new("Rome").category(TripadvisorCategory::Restaurants);
+///
+/// let results = client.search_engine(query).await?;
+/// for place in results.places.unwrap_or_default() {
+///     println!("{} {:?} ({:?} reviews)", place.title, place.rating, place.reviews);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct TripadvisorQuery {
+    q: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ssrc: Option<TripadvisorCategory>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tripadvisor_domain: Option<String>,
+
+    #[serde(rename = "gl", skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<u32>,
+}
+
+impl TripadvisorQuery {
+    /// Create a Tripadvisor search
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            q: query.into(),
+            ssrc: None,
+            tripadvisor_domain: None,
+            country: None,
+            offset: None,
+        }
+    }
+
+    /// Restrict results to a kind of place (ssrc parameter)
+    pub fn category(mut self, category: TripadvisorCategory) -> Self {
+        self.ssrc = Some(category);
+        self
+    }
+
+    /// Set the Tripadvisor domain (tripadvisor_domain parameter)
+    /// Examples: "tripadvisor.com", "tripadvisor.co.uk", "tripadvisor.it"
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.tripadvisor_domain = Some(domain.into());
+        self
+    }
+
+    /// Set the country (gl parameter)
+    pub fn country(mut self, gl: impl Into<String>) -> Self {
+        self.country = Some(gl.into());
+        self
+    }
+
+    /// Set the offset for pagination (offset parameter)
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+impl EngineQuery for TripadvisorQuery {
+    type Response = TripadvisorResults;
+
+    fn engine(&self) -> &str {
+        "tripadvisor"
+    }
+
+    fn next_page(&self, response: &TripadvisorResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        let returned = response.places.as_ref().map_or(0, Vec::len) as u32;
+        (has_next && returned > 0).then(|| {
+            let offset = self.offset.unwrap_or(0) + returned;
+            self.clone().offset(offset)
+        })
+    }
+}
+
+/// Query for the `tripadvisor_reviews` engine.
+///
+/// Use [`SerpClient::tripadvisor_reviews_stream`] to iterate over every review.
+#[derive(Debug, Clone, Serialize)]
+pub struct TripadvisorReviewsQuery {
+    place_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tripadvisor_domain: Option<String>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl TripadvisorReviewsQuery {
+    /// Create a reviews query for a Tripadvisor place ID
+    pub fn new(place_id: impl Into<String>) -> Self {
+        Self {
+            place_id: place_id.into(),
+            tripadvisor_domain: None,
+            language: None,
+            page: None,
+        }
+    }
+
+    /// Set the Tripadvisor domain (tripadvisor_domain parameter)
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.tripadvisor_domain = Some(domain.into());
+        self
+    }
+
+    /// Only return reviews written in a language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Set the one-based page number (page parameter)
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+impl EngineQuery for TripadvisorReviewsQuery {
+    type Response = TripadvisorReviewsResults;
+
+    fn engine(&self) -> &str {
+        "tripadvisor_reviews"
+    }
+
+    fn next_page(&self, response: &TripadvisorReviewsResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        has_next.then(|| {
+            let page = self.page.unwrap_or(1) + 1;
+            self.clone().page(page)
+        })
+    }
+}
+
+impl SerpClient {
+    /// Stream individual Tripadvisor reviews across pages
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::StreamExt;
+    /// use serp_sdk::{SerpClient, StreamConfig};
+    /// use serp_sdk::engines::tripadvisor::TripadvisorReviewsQuery;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SerpClient::new("test")?;
+    /// let query = TripadvisorReviewsQuery::new("1795155").language("en");
+    ///
+    /// let mut reviews = client.tripadvisor_reviews_stream(query, StreamConfig::new().max_pages(5));
+    /// while let Some(review) = reviews.next().await {
+    ///     let review = review?;
+    ///     let replied = review.response.is_some();
+    ///     println!("{:?} {:?} replied: {}", review.rating, review.title, replied);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn tripadvisor_reviews_stream(
+        &self,
+        query: TripadvisorReviewsQuery,
+        config: StreamConfig,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<TripadvisorReview>> + Send + '_>> {
+        self.engine_items_stream(query, config, |page| page.reviews.unwrap_or_default())
+    }
+}
+
+/// Results of a `tripadvisor` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct TripadvisorResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub places: Option<Vec<TripadvisorPlace>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Place listed on Tripadvisor
+#[derive(Debug, Deserialize, Clone)]
+pub struct TripadvisorPlace {
+    pub position: Option<u32>,
+    pub title: String,
+    /// ID accepted by [`TripadvisorReviewsQuery`]
+    pub place_id: Option<String>,
+    /// e.g. "HOTEL", "EATERY" or "ATTRACTION"
+    pub place_type: Option<String>,
+    pub link: Option<String>,
+    pub description: Option<String>,
+    pub rating: Option<f32>,
+    pub reviews: Option<u32>,
+    pub location: Option<String>,
+    pub thumbnail: Option<String>,
+}
+
+/// Results of a `tripadvisor_reviews` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct TripadvisorReviewsResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub reviews: Option<Vec<TripadvisorReview>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Tripadvisor review
+#[derive(Debug, Deserialize, Clone)]
+pub struct TripadvisorReview {
+    pub position: Option<u32>,
+    pub title: Option<String>,
+    pub snippet: Option<String>,
+    pub link: Option<String>,
+    pub rating: Option<f32>,
+    /// e.g. "March 2024"
+    pub date: Option<String>,
+    pub author: Option<TripadvisorAuthor>,
+    pub photos: Option<Vec<TripadvisorPhoto>>,
+    /// Reply from the owner or management
+    pub response: Option<OwnerResponse>,
+}
+
+/// Author of a Tripadvisor review
+#[derive(Debug, Deserialize, Clone)]
+pub struct TripadvisorAuthor {
+    pub username: Option<String>,
+    pub link: Option<String>,
+    pub avatar: Option<String>,
+    pub hometown: Option<String>,
+    pub contributions: Option<u32>,
+}
+
+/// Photo attached to a review
+#[derive(Debug, Deserialize, Clone)]
+pub struct TripadvisorPhoto {
+    pub link: Option<String>,
+    pub thumbnail: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tripadvisor_query_params() {
+        let query = TripadvisorQuery::new("Rome")
+            .category(TripadvisorCategory::ThingsToDo)
+            .domain("tripadvisor.it");
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("q".to_string(), "Rome".to_string()),
+                ("ssrc".to_string(), "A".to_string()),
+                (
+                    "tripadvisor_domain".to_string(),
+                    "tripadvisor.it".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tripadvisor_reviews_parsing_and_next_page() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "reviews": [{
+                "position": 1,
+                "title": "Worth the wait",
+                "snippet": "Friendly staff and a view of the Pantheon.",
+                "rating": 5,
+                "date": "March 2024",
+                "author": {"username": "traveller42", "hometown": "Leeds, UK", "contributions": 18},
+                "photos": [{"link": "https://media-cdn.tripadvisor.com/p1.jpg"}],
+                "response": {"date": "March 30, 2024", "snippet": "Grazie for visiting!"}
+            }],
+            "serpapi_pagination": {"next": "https://serpapi.com/search.json?page=2"}
+        }"#;
+        let results: TripadvisorReviewsResults = serde_json::from_str(json).unwrap();
+        let review = &results.reviews.as_ref().unwrap()[0];
+        assert_eq!(
+            review.author.as_ref().unwrap().username.as_deref(),
+            Some("traveller42")
+        );
+        assert_eq!(
+            review.response.as_ref().unwrap().snippet.as_deref(),
+            Some("Grazie for visiting!")
+        );
+
+        let next = TripadvisorReviewsQuery::new("1795155")
+            .next_page(&results)
+            .unwrap();
+        assert_eq!(next.page, Some(2));
+    }
+}
@@ -0,0 +1,356 @@
+#![allow(missing_docs)]
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+
+use crate::{
+    client::SerpClient,
+    engines::{check_price_range, has_next_page, EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    response::{ListingSummary, Price, ProductListing, SearchMetadata, SerpapiPagination},
+    streaming::StreamConfig,
+};
+
+/// Walmart serves at most this many result pages
+const MAX_PAGE: u32 = 100;
+
+/// Sort order for Walmart results (sort parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WalmartSort {
+    BestMatch,
+    PriceLow,
+    PriceHigh,
+    BestSeller,
+    RatingHigh,
+    New,
+}
+
+/// Query for the `walmart` engine.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::SerpClient;
+/// use serp_sdk::engines::walmart::{WalmartQuery, WalmartSort};
+/// use serp_sdk::response::ProductListing;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = WalmartQuery::new("air fryer")
+///     .price_range(None, Some(80.0))?
+///     .sort_by(WalmartSort::RatingHigh)
+///     .store("2280");
+///
+/// let results = client.search_engine(query).await?;
+/// for product in results.organic_results.unwrap_or_default() {
+///     println!("{} {:?}", product.title, product.summary().price);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct WalmartQuery {
+    query: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<WalmartSort>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_price: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_price: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl WalmartQuery {
+    /// Create a Walmart search
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            sort: None,
+            min_price: None,
+            max_price: None,
+            store_id: None,
+            page: None,
+        }
+    }
+
+    /// Set the sort order (sort parameter)
+    pub fn sort_by(mut self, sort: WalmartSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Only include products priced within a range (min_price/max_price parameters)
+    pub fn price_range(mut self, min: Option<f64>, max: Option<f64>) -> SerpResult<Self> {
+        check_price_range(min, max)?;
+        self.min_price = min;
+        self.max_price = max;
+        Ok(self)
+    }
+
+    /// Show availability and prices for a store (store_id parameter)
+    pub fn store(mut self, store_id: impl Into<String>) -> Self {
+        self.store_id = Some(store_id.into());
+        self
+    }
+
+    /// Set the one-based page number (page parameter, up to 100)
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+impl EngineQuery for WalmartQuery {
+    type Response = WalmartResults;
+
+    fn engine(&self) -> &str {
+        "walmart"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        if self.page.is_some_and(|page| page == 0 || page > MAX_PAGE) {
+            return Err(SerpError::InvalidParameter(format!(
+                "page must be between 1 and {}",
+                MAX_PAGE
+            )));
+        }
+        Ok(())
+    }
+
+    fn next_page(&self, response: &WalmartResults) -> Option<Self> {
+        let page = self.page.unwrap_or(1) + 1;
+        (has_next_page(&response.serpapi_pagination) && page <= MAX_PAGE)
+            .then(|| self.clone().page(page))
+    }
+}
+
+/// Query for the `walmart_product` engine.
+#[derive(Debug, Clone, Serialize)]
+pub struct WalmartProductQuery {
+    product_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store_id: Option<String>,
+}
+
+impl WalmartProductQuery {
+    /// Create a product query for a Walmart product ID (us_item_id)
+    pub fn new(product_id: impl Into<String>) -> Self {
+        Self {
+            product_id: product_id.into(),
+            store_id: None,
+        }
+    }
+
+    /// Show availability and prices for a store (store_id parameter)
+    pub fn store(mut self, store_id: impl Into<String>) -> Self {
+        self.store_id = Some(store_id.into());
+        self
+    }
+}
+
+impl EngineQuery for WalmartProductQuery {
+    type Response = WalmartProductResults;
+
+    fn engine(&self) -> &str {
+        "walmart_product"
+    }
+}
+
+impl SerpClient {
+    /// Stream individual Walmart products across pages
+    pub fn walmart_stream(
+        &self,
+        query: WalmartQuery,
+        config: StreamConfig,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<WalmartProduct>> + Send + '_>> {
+        self.engine_items_stream(query, config, |page| {
+            page.organic_results.unwrap_or_default()
+        })
+    }
+}
+
+/// Results of a `walmart` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct WalmartResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub organic_results: Option<Vec<WalmartProduct>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Walmart product listing
+#[derive(Debug, Deserialize, Clone)]
+pub struct WalmartProduct {
+    /// Product ID for [`WalmartProductQuery`]
+    pub us_item_id: Option<String>,
+    pub product_id: Option<String>,
+    pub title: String,
+    pub description: Option<String>,
+    pub thumbnail: Option<String>,
+    pub rating: Option<f32>,
+    pub reviews: Option<u64>,
+    pub seller_id: Option<String>,
+    pub seller_name: Option<String>,
+    pub out_of_stock: Option<bool>,
+    pub primary_offer: Option<WalmartOffer>,
+    pub product_page_url: Option<String>,
+    pub serpapi_product_page_url: Option<String>,
+}
+
+impl ProductListing for WalmartProduct {
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn link(&self) -> Option<&str> {
+        self.product_page_url.as_deref()
+    }
+
+    fn summary(&self) -> ListingSummary {
+        let offer = self.primary_offer.as_ref();
+        ListingSummary {
+            price: offer.and_then(|o| walmart_price(o.offer_price, o.currency.as_deref())),
+            old_price: None,
+            rating: self.rating,
+            reviews: self.reviews,
+            seller: self.seller_name.clone(),
+        }
+    }
+}
+
+/// Walmart's main offer for a product
+#[derive(Debug, Deserialize, Clone)]
+pub struct WalmartOffer {
+    pub offer_id: Option<String>,
+    pub offer_price: Option<f64>,
+    pub min_price: Option<f64>,
+    pub currency: Option<String>,
+}
+
+/// Results of a `walmart_product` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct WalmartProductResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub product_result: Option<WalmartProductDetails>,
+}
+
+/// Walmart product page
+#[derive(Debug, Deserialize, Clone)]
+pub struct WalmartProductDetails {
+    pub us_item_id: Option<String>,
+    pub product_id: Option<String>,
+    pub title: String,
+    pub short_description_html: Option<String>,
+    pub manufacturer: Option<String>,
+    pub product_page_url: Option<String>,
+    pub price_map: Option<WalmartPriceMap>,
+    pub rating: Option<f32>,
+    pub reviews: Option<u64>,
+    pub seller_id: Option<String>,
+    pub seller_name: Option<String>,
+    pub images: Option<Vec<String>>,
+}
+
+impl ProductListing for WalmartProductDetails {
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn link(&self) -> Option<&str> {
+        self.product_page_url.as_deref()
+    }
+
+    fn summary(&self) -> ListingSummary {
+        let prices = self.price_map.as_ref();
+        ListingSummary {
+            price: prices.and_then(|p| walmart_price(p.price, p.currency.as_deref())),
+            old_price: None,
+            rating: self.rating,
+            reviews: self.reviews,
+            seller: self.seller_name.clone(),
+        }
+    }
+}
+
+/// Current price of a product page
+#[derive(Debug, Deserialize, Clone)]
+pub struct WalmartPriceMap {
+    pub price: Option<f64>,
+    pub currency: Option<String>,
+}
+
+/// Walmart prices are numeric and in US dollars unless stated otherwise
+fn walmart_price(amount: Option<f64>, currency: Option<&str>) -> Option<Price> {
+    Some(Price {
+        amount: amount?,
+        currency: Some(currency.unwrap_or("USD").to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_walmart_query_params() {
+        let query = WalmartQuery::new("air fryer")
+            .sort_by(WalmartSort::PriceLow)
+            .price_range(Some(20.0), Some(80.0))
+            .unwrap()
+            .store("2280");
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("query".to_string(), "air fryer".to_string()),
+                ("sort".to_string(), "price_low".to_string()),
+                ("min_price".to_string(), "20.0".to_string()),
+                ("max_price".to_string(), "80.0".to_string()),
+                ("store_id".to_string(), "2280".to_string()),
+            ]
+        );
+        assert!(WalmartQuery::new("air fryer").page(101).validate().is_err());
+        assert!(WalmartQuery::new("air fryer").page(100).validate().is_ok());
+    }
+
+    #[test]
+    fn test_walmart_results_and_summary() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "organic_results": [{
+                "us_item_id": "123456",
+                "title": "Air Fryer 5qt",
+                "rating": 4.4,
+                "reviews": 812,
+                "seller_name": "Walmart.com",
+                "primary_offer": {"offer_price": 59.0}
+            }],
+            "serpapi_pagination": {"current": 1, "next": "https://serpapi.com/search.json?page=2"}
+        }"#;
+        let results: WalmartResults = serde_json::from_str(json).unwrap();
+        let summary = results.organic_results.as_ref().unwrap()[0].summary();
+        assert_eq!(
+            summary.price,
+            Some(Price {
+                amount: 59.0,
+                currency: Some("USD".to_string())
+            })
+        );
+        assert_eq!(summary.seller.as_deref(), Some("Walmart.com"));
+
+        let next = WalmartQuery::new("air fryer").next_page(&results).unwrap();
+        assert_eq!(next.page, Some(2));
+    }
+}
@@ -0,0 +1,154 @@
+#![allow(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    engines::{has_next_page, EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    response::{OrganicResult, RelatedSearch, SearchMetadata, SerpapiPagination},
+};
+
+/// Query for the `yahoo` engine.
+///
+/// Pages are reached with a one-based `b` offset advanced by the number of
+/// results each page returned.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::SerpClient;
+/// use serp_sdk::engines::yahoo::YahooQuery;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = YahooQuery::new("coffee roasters")
+///     .language("lang_en")
+///     .country("us");
+///
+/// let results = client.search_engine(query).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct YahooQuery {
+    p: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vl: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vc: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    yahoo_domain: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    b: Option<u32>,
+}
+
+impl YahooQuery {
+    /// Create a Yahoo search
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            p: query.into(),
+            vl: None,
+            vc: None,
+            yahoo_domain: None,
+            b: None,
+        }
+    }
+
+    /// Restrict results to a language (vl parameter)
+    /// Examples: "lang_en", "lang_fr", "lang_ja"
+    pub fn language(mut self, vl: impl Into<String>) -> Self {
+        self.vl = Some(vl.into());
+        self
+    }
+
+    /// Restrict results to a country (vc parameter)
+    pub fn country(mut self, vc: impl Into<String>) -> Self {
+        self.vc = Some(vc.into());
+        self
+    }
+
+    /// Set the Yahoo domain (yahoo_domain parameter)
+    /// Examples: "uk", "fr", "de"
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.yahoo_domain = Some(domain.into());
+        self
+    }
+
+    /// Set the one-based offset of the first result (b parameter)
+    pub fn offset(mut self, b: u32) -> Self {
+        self.b = Some(b);
+        self
+    }
+}
+
+impl EngineQuery for YahooQuery {
+    type Response = YahooResults;
+
+    fn engine(&self) -> &str {
+        "yahoo"
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        if self.b == Some(0) {
+            return Err(SerpError::InvalidParameter(
+                "Yahoo results start at 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn next_page(&self, response: &YahooResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        let returned = response.organic_results.as_ref().map_or(0, Vec::len) as u32;
+        (has_next && returned > 0).then(|| Self {
+            b: Some(self.b.unwrap_or(1) + returned),
+            ..self.clone()
+        })
+    }
+}
+
+/// Results of a `yahoo` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct YahooResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub organic_results: Option<Vec<OrganicResult>>,
+    pub related_searches: Option<Vec<RelatedSearch>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_yahoo_params_and_next_page() {
+        let query = YahooQuery::new("coffee").language("lang_en");
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("p".to_string(), "coffee".to_string()),
+                ("vl".to_string(), "lang_en".to_string()),
+            ]
+        );
+        assert!(query.clone().offset(0).validate().is_err());
+
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "organic_results": [
+                {"position": 1, "title": "Coffee", "link": "https://en.wikipedia.org/wiki/Coffee"},
+                {"position": 2, "title": "Coffee beans", "link": "https://example.com/beans"}
+            ],
+            "serpapi_pagination": {"next": "https://serpapi.com/search.json?b=3"}
+        }"#;
+        let results: YahooResults = serde_json::from_str(json).unwrap();
+        let next = query.next_page(&results).unwrap();
+        assert_eq!(next.b, Some(3));
+        assert_eq!(next.next_page(&results).unwrap().b, Some(5));
+    }
+}
@@ -0,0 +1,137 @@
+#![allow(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    engines::{has_next_page, EngineParameters, EngineQuery},
+    response::{OrganicResult, SearchMetadata, SerpapiPagination},
+};
+
+/// Query for the `yandex` engine.
+///
+/// Pages are reached with a zero-based `p` page number.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::SerpClient;
+/// use serp_sdk::engines::yandex::YandexQuery;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// // 213 is Moscow
+/// let query = YandexQuery::new("кофе").region(213).domain("yandex.ru");
+///
+/// let results = client.search_engine(query).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct YandexQuery {
+    text: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lr: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lang: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    yandex_domain: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    p: Option<u32>,
+}
+
+impl YandexQuery {
+    /// Create a Yandex search
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            lr: None,
+            lang: None,
+            yandex_domain: None,
+            p: None,
+        }
+    }
+
+    /// Set the Yandex region ID to search from (lr parameter)
+    /// Examples: 213 (Moscow), 2 (Saint Petersburg), 84 (USA)
+    pub fn region(mut self, lr: u32) -> Self {
+        self.lr = Some(lr);
+        self
+    }
+
+    /// Set the result language (lang parameter)
+    /// Examples: "en", "ru", "tr"
+    pub fn language(mut self, lang: impl Into<String>) -> Self {
+        self.lang = Some(lang.into());
+        self
+    }
+
+    /// Set the Yandex domain (yandex_domain parameter)
+    /// Examples: "yandex.com", "yandex.ru", "yandex.com.tr"
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.yandex_domain = Some(domain.into());
+        self
+    }
+
+    /// Set the zero-based page number (p parameter)
+    pub fn page(mut self, p: u32) -> Self {
+        self.p = Some(p);
+        self
+    }
+}
+
+impl EngineQuery for YandexQuery {
+    type Response = YandexResults;
+
+    fn engine(&self) -> &str {
+        "yandex"
+    }
+
+    fn next_page(&self, response: &YandexResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        has_next.then(|| {
+            let page = self.p.unwrap_or(0) + 1;
+            self.clone().page(page)
+        })
+    }
+}
+
+/// Results of a `yandex` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct YandexResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub organic_results: Option<Vec<OrganicResult>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_yandex_params_and_next_page() {
+        let query = YandexQuery::new("coffee").region(213).language("en");
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("text".to_string(), "coffee".to_string()),
+                ("lr".to_string(), "213".to_string()),
+                ("lang".to_string(), "en".to_string()),
+            ]
+        );
+
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "organic_results": [{"position": 1, "title": "Coffee", "link": "https://example.com"}],
+            "serpapi_pagination": {"current": 1, "next": "https://serpapi.com/search.json?p=1"}
+        }"#;
+        let results: YandexResults = serde_json::from_str(json).unwrap();
+        let next = query.next_page(&results).unwrap();
+        assert_eq!(next.p, Some(1));
+    }
+}
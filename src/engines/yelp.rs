@@ -0,0 +1,444 @@
+#![allow(missing_docs)]
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+
+use crate::{
+    client::SerpClient,
+    engines::{has_next_page, EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    response::{SearchMetadata, SerpapiPagination},
+    streaming::StreamConfig,
+};
+
+/// Sort order for Yelp businesses (sortby parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum YelpSort {
+    Recommended,
+    Rating,
+    ReviewCount,
+}
+
+/// Sort order for Yelp reviews (sortby parameter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum YelpReviewSort {
+    #[serde(rename = "relevance_desc")]
+    Relevance,
+    #[serde(rename = "date_desc")]
+    Newest,
+    #[serde(rename = "date_asc")]
+    Oldest,
+    #[serde(rename = "rating_desc")]
+    HighestRated,
+    #[serde(rename = "rating_asc")]
+    LowestRated,
+    #[serde(rename = "elites_desc")]
+    Elites,
+}
+
+/// Query for the `yelp` engine.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::SerpClient;
+/// use serp_sdk::engines::yelp::{YelpQuery, YelpSort};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::new("test")?;
+/// let query = YelpQuery::new("ramen", "Seattle, WA").sort_by(YelpSort::Rating);
+///
+/// let results = client.search_engine(query).await?;
+/// for business in results.organic_results.unwrap_or_default() {
+///     println!("{} {:?} ({:?} reviews)", business.title, business.rating, business.reviews);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct YelpQuery {
+    find_desc: String,
+
+    find_loc: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    yelp_domain: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cflt: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sortby: Option<YelpSort>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start: Option<u32>,
+}
+
+impl YelpQuery {
+    /// Create a Yelp search for businesses near a location
+    pub fn new(description: impl Into<String>, location: impl Into<String>) -> Self {
+        Self {
+            find_desc: description.into(),
+            find_loc: location.into(),
+            yelp_domain: None,
+            cflt: None,
+            sortby: None,
+            start: None,
+        }
+    }
+
+    /// Set the Yelp domain (yelp_domain parameter)
+    /// Examples: "yelp.com", "yelp.co.uk", "yelp.de"
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.yelp_domain = Some(domain.into());
+        self
+    }
+
+    /// Restrict results to a category (cflt parameter)
+    /// Examples: "restaurants", "plumbing", "hair"
+    pub fn category(mut self, category: impl Into<String>) -> Self {
+        self.cflt = Some(category.into());
+        self
+    }
+
+    /// Set the sort order (sortby parameter)
+    pub fn sort_by(mut self, sort: YelpSort) -> Self {
+        self.sortby = Some(sort);
+        self
+    }
+
+    /// Set the offset for pagination (start parameter)
+    pub fn offset(mut self, start: u32) -> Self {
+        self.start = Some(start);
+        self
+    }
+}
+
+impl EngineQuery for YelpQuery {
+    type Response = YelpResults;
+
+    fn engine(&self) -> &str {
+        "yelp"
+    }
+
+    fn next_page(&self, response: &YelpResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        let returned = response.organic_results.as_ref().map_or(0, Vec::len) as u32;
+        (has_next && returned > 0).then(|| {
+            let start = self.start.unwrap_or(0) + returned;
+            self.clone().offset(start)
+        })
+    }
+}
+
+/// Query for the `yelp_reviews` engine.
+///
+/// Use [`SerpClient::yelp_reviews_stream`] to iterate over every review.
+#[derive(Debug, Clone, Serialize)]
+pub struct YelpReviewsQuery {
+    place_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    yelp_domain: Option<String>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sortby: Option<YelpReviewSort>,
+
+    #[serde(rename = "q", skip_serializing_if = "Option::is_none")]
+    filter: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start: Option<u32>,
+}
+
+impl YelpReviewsQuery {
+    /// Create a reviews query for a Yelp place ID (from a business's `place_ids`)
+    pub fn new(place_id: impl Into<String>) -> Self {
+        Self {
+            place_id: place_id.into(),
+            yelp_domain: None,
+            language: None,
+            sortby: None,
+            filter: None,
+            num: None,
+            start: None,
+        }
+    }
+
+    /// Set the Yelp domain (yelp_domain parameter)
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.yelp_domain = Some(domain.into());
+        self
+    }
+
+    /// Only return reviews written in a language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Set the sort order (sortby parameter)
+    pub fn sort_by(mut self, sort: YelpReviewSort) -> Self {
+        self.sortby = Some(sort);
+        self
+    }
+
+    /// Only return reviews containing the given text (q parameter)
+    pub fn filter(mut self, text: impl Into<String>) -> Self {
+        self.filter = Some(text.into());
+        self
+    }
+
+    /// Set the number of reviews per page (1-49)
+    pub fn limit(mut self, num: u32) -> SerpResult<Self> {
+        if num == 0 || num > 49 {
+            return Err(SerpError::InvalidParameter(
+                "limit must be between 1 and 49".to_string(),
+            ));
+        }
+        self.num = Some(num);
+        Ok(self)
+    }
+
+    /// Set the offset for pagination (start parameter)
+    pub fn offset(mut self, start: u32) -> Self {
+        self.start = Some(start);
+        self
+    }
+}
+
+impl EngineQuery for YelpReviewsQuery {
+    type Response = YelpReviewsResults;
+
+    fn engine(&self) -> &str {
+        "yelp_reviews"
+    }
+
+    fn next_page(&self, response: &YelpReviewsResults) -> Option<Self> {
+        let has_next = has_next_page(&response.serpapi_pagination);
+        let returned = response.reviews.as_ref().map_or(0, Vec::len) as u32;
+        (has_next && returned > 0).then(|| {
+            let start = self.start.unwrap_or(0) + returned;
+            self.clone().offset(start)
+        })
+    }
+}
+
+impl SerpClient {
+    /// Stream individual Yelp reviews across pages
+    ///
+    /// Pages are requested with an increasing `start` offset until SerpAPI stops
+    /// returning a next page or `config.max_pages` is reached.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::StreamExt;
+    /// use serp_sdk::{SerpClient, StreamConfig};
+    /// use serp_sdk::engines::yelp::{YelpReviewSort, YelpReviewsQuery};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SerpClient::new("test")?;
+    /// let query = YelpReviewsQuery::new("ED7A7vDdg8yLNKJTSVHHmg").sort_by(YelpReviewSort::Newest);
+    ///
+    /// let mut reviews = client.yelp_reviews_stream(query, StreamConfig::new().max_pages(5));
+    /// while let Some(review) = reviews.next().await {
+    ///     let review = review?;
+    ///     println!("{:?} {:?}", review.rating, review.text());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn yelp_reviews_stream(
+        &self,
+        query: YelpReviewsQuery,
+        config: StreamConfig,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<YelpReview>> + Send + '_>> {
+        self.engine_items_stream(query, config, |page| page.reviews.unwrap_or_default())
+    }
+}
+
+/// Results of a `yelp` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct YelpResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub organic_results: Option<Vec<YelpBusiness>>,
+    pub ads_results: Option<Vec<YelpBusiness>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Business listed on Yelp
+#[derive(Debug, Deserialize, Clone)]
+pub struct YelpBusiness {
+    pub position: Option<u32>,
+    pub title: String,
+    /// IDs accepted by [`YelpReviewsQuery`]
+    pub place_ids: Option<Vec<String>>,
+    pub link: Option<String>,
+    pub reviews_link: Option<String>,
+    pub categories: Option<Vec<YelpCategory>>,
+    /// e.g. "$$"
+    pub price: Option<String>,
+    pub rating: Option<f32>,
+    pub reviews: Option<u32>,
+    pub neighborhoods: Option<String>,
+    pub phone: Option<String>,
+    pub snippet: Option<String>,
+    pub thumbnail: Option<String>,
+}
+
+impl YelpBusiness {
+    /// Place ID to request reviews for
+    pub fn place_id(&self) -> Option<&str> {
+        self.place_ids.as_ref()?.first().map(String::as_str)
+    }
+}
+
+/// Yelp business category
+#[derive(Debug, Deserialize, Clone)]
+pub struct YelpCategory {
+    pub title: String,
+    pub link: Option<String>,
+}
+
+/// Results of a `yelp_reviews` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct YelpReviewsResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub reviews: Option<Vec<YelpReview>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Yelp review
+#[derive(Debug, Deserialize, Clone)]
+pub struct YelpReview {
+    pub position: Option<u32>,
+    pub user: Option<YelpUser>,
+    pub comment: Option<YelpComment>,
+    /// e.g. "7/21/2024"
+    pub date: Option<String>,
+    pub rating: Option<f32>,
+    pub photos: Option<Vec<YelpPhoto>>,
+    pub feedback: Option<YelpFeedback>,
+    pub owner_replies: Option<Vec<YelpOwnerReply>>,
+}
+
+impl YelpReview {
+    /// Review text
+    pub fn text(&self) -> Option<&str> {
+        self.comment.as_ref()?.text.as_deref()
+    }
+}
+
+/// Author of a Yelp review or reply
+#[derive(Debug, Deserialize, Clone)]
+pub struct YelpUser {
+    pub name: String,
+    pub user_id: Option<String>,
+    pub link: Option<String>,
+    pub thumbnail: Option<String>,
+    pub address: Option<String>,
+    pub reviews: Option<u32>,
+    pub photos: Option<u32>,
+    pub elite_year: Option<u32>,
+}
+
+/// Text of a review or reply
+#[derive(Debug, Deserialize, Clone)]
+pub struct YelpComment {
+    pub text: Option<String>,
+    pub language: Option<String>,
+}
+
+/// Photo attached to a review
+#[derive(Debug, Deserialize, Clone)]
+pub struct YelpPhoto {
+    pub link: Option<String>,
+    pub caption: Option<String>,
+}
+
+/// Reactions to a review
+#[derive(Debug, Deserialize, Clone)]
+pub struct YelpFeedback {
+    pub useful: Option<u32>,
+    pub funny: Option<u32>,
+    pub cool: Option<u32>,
+}
+
+/// Reply from the business owner
+#[derive(Debug, Deserialize, Clone)]
+pub struct YelpOwnerReply {
+    pub user: Option<YelpUser>,
+    pub comment: Option<YelpComment>,
+    pub date: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_yelp_query_params() {
+        let query = YelpQuery::new("ramen", "Seattle, WA")
+            .category("restaurants")
+            .sort_by(YelpSort::ReviewCount);
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("find_desc".to_string(), "ramen".to_string()),
+                ("find_loc".to_string(), "Seattle, WA".to_string()),
+                ("cflt".to_string(), "restaurants".to_string()),
+                ("sortby".to_string(), "review_count".to_string()),
+            ]
+        );
+
+        let reviews = YelpReviewsQuery::new("abc").sort_by(YelpReviewSort::Newest);
+        assert!(reviews
+            .params()
+            .unwrap()
+            .contains(&("sortby".to_string(), "date_desc".to_string())));
+        assert!(YelpReviewsQuery::new("abc").limit(50).is_err());
+    }
+
+    #[test]
+    fn test_yelp_reviews_parsing_and_next_page() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "reviews": [{
+                "position": 1,
+                "user": {"name": "Alex K.", "user_id": "u1", "reviews": 42},
+                "comment": {"text": "Rich broth, long line.", "language": "en"},
+                "date": "7/21/2024",
+                "rating": 4,
+                "photos": [{"link": "https://s3-media0.fl.yelpcdn.com/p1.jpg", "caption": "Tonkotsu"}],
+                "feedback": {"useful": 3, "funny": 0, "cool": 1},
+                "owner_replies": [{"comment": {"text": "Thanks Alex!"}, "date": "7/22/2024"}]
+            }],
+            "serpapi_pagination": {"next": "https://serpapi.com/search.json?start=10"}
+        }"#;
+        let results: YelpReviewsResults = serde_json::from_str(json).unwrap();
+        let review = &results.reviews.as_ref().unwrap()[0];
+        assert_eq!(review.text(), Some("Rich broth, long line."));
+        let reply = &review.owner_replies.as_ref().unwrap()[0];
+        assert_eq!(
+            reply.comment.as_ref().unwrap().text.as_deref(),
+            Some("Thanks Alex!")
+        );
+
+        let next = YelpReviewsQuery::new("abc").next_page(&results).unwrap();
+        assert_eq!(next.start, Some(1));
+    }
+}
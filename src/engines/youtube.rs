@@ -0,0 +1,445 @@
+#![allow(missing_docs)]
+
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::{
+    encoding::base64_encode,
+    engines::{EngineParameters, EngineQuery},
+    error::{SerpError, SerpResult},
+    parse,
+    response::{SearchMetadata, SerpapiPagination},
+};
+
+/// Upload date filter for YouTube searches
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UploadDate {
+    LastHour,
+    Today,
+    ThisWeek,
+    ThisMonth,
+    ThisYear,
+}
+
+/// Result type filter for YouTube searches
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YouTubeResultType {
+    Video,
+    Channel,
+    Playlist,
+    Movie,
+}
+
+/// Video duration filter for YouTube searches
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VideoDuration {
+    /// Under 4 minutes
+    Short,
+    /// 4 to 20 minutes
+    Medium,
+    /// Over 20 minutes
+    Long,
+}
+
+/// Sort order for YouTube searches
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YouTubeSort {
+    Relevance,
+    UploadDate,
+    ViewCount,
+    Rating,
+}
+
+/// Query for the `youtube` engine.
+///
+/// Filters are encoded into YouTube's `sp` parameter. Subsequent pages are
+/// requested with the `sp` token SerpAPI returns as `next_page_token`.
+///
+/// # Examples
+///
+/// ```rust
+/// use serp_sdk::engines::youtube::{UploadDate, VideoDuration, YouTubeQuery, YouTubeResultType};
+///
+/// let query = YouTubeQuery::new("rust async")
+///     .upload_date(UploadDate::ThisMonth)
+///     .result_type(YouTubeResultType::Video)
+///     .duration(VideoDuration::Long);
+///
+/// assert_eq!(query.sp().as_deref(), Some("EgYIBBABGAI="));
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct YouTubeQuery {
+    search_query: String,
+
+    #[serde(rename = "gl", skip_serializing_if = "Option::is_none")]
+    geolocation: Option<String>,
+
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip)]
+    upload_date: Option<UploadDate>,
+
+    #[serde(skip)]
+    result_type: Option<YouTubeResultType>,
+
+    #[serde(skip)]
+    duration: Option<VideoDuration>,
+
+    #[serde(skip)]
+    sort_by: Option<YouTubeSort>,
+
+    #[serde(skip)]
+    raw_sp: Option<String>,
+}
+
+impl YouTubeQuery {
+    /// Create a new YouTube search
+    pub fn new(search_query: impl Into<String>) -> Self {
+        Self {
+            search_query: search_query.into(),
+            geolocation: None,
+            language: None,
+            upload_date: None,
+            result_type: None,
+            duration: None,
+            sort_by: None,
+            raw_sp: None,
+        }
+    }
+
+    /// Set the country (gl parameter)
+    pub fn country(mut self, gl: impl Into<String>) -> Self {
+        self.geolocation = Some(gl.into());
+        self
+    }
+
+    /// Set the interface language (hl parameter)
+    pub fn language(mut self, hl: impl Into<String>) -> Self {
+        self.language = Some(hl.into());
+        self
+    }
+
+    /// Only return results uploaded within a period
+    pub fn upload_date(mut self, upload_date: UploadDate) -> Self {
+        self.upload_date = Some(upload_date);
+        self
+    }
+
+    /// Only return results of a type
+    pub fn result_type(mut self, result_type: YouTubeResultType) -> Self {
+        self.result_type = Some(result_type);
+        self
+    }
+
+    /// Only return videos of a duration
+    pub fn duration(mut self, duration: VideoDuration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Set the sort order
+    pub fn sort_by(mut self, sort: YouTubeSort) -> Self {
+        self.sort_by = Some(sort);
+        self
+    }
+
+    /// Set a raw `sp` value (filter or page token), overriding typed filters
+    pub fn raw_sp(mut self, sp: impl Into<String>) -> Self {
+        self.raw_sp = Some(sp.into());
+        self
+    }
+
+    /// Get the `sp` value sent with this query, if any
+    pub fn sp(&self) -> Option<String> {
+        if let Some(sp) = &self.raw_sp {
+            return Some(sp.clone());
+        }
+
+        // `sp` is a base64 protobuf: field 1 is the sort order, field 2 a
+        // nested message of filters (1: upload date, 2: type, 3: duration).
+        let mut filters = Vec::new();
+        if let Some(upload_date) = self.upload_date {
+            let value = match upload_date {
+                UploadDate::LastHour => 1,
+                UploadDate::Today => 2,
+                UploadDate::ThisWeek => 3,
+                UploadDate::ThisMonth => 4,
+                UploadDate::ThisYear => 5,
+            };
+            filters.extend([0x08, value]);
+        }
+        if let Some(result_type) = self.result_type {
+            let value = match result_type {
+                YouTubeResultType::Video => 1,
+                YouTubeResultType::Channel => 2,
+                YouTubeResultType::Playlist => 3,
+                YouTubeResultType::Movie => 4,
+            };
+            filters.extend([0x10, value]);
+        }
+        if let Some(duration) = self.duration {
+            let value = match duration {
+                VideoDuration::Short => 1,
+                VideoDuration::Long => 2,
+                VideoDuration::Medium => 3,
+            };
+            filters.extend([0x18, value]);
+        }
+
+        let mut message = Vec::new();
+        if let Some(sort) = self.sort_by {
+            let value = match sort {
+                YouTubeSort::Relevance => 0,
+                YouTubeSort::Rating => 1,
+                YouTubeSort::UploadDate => 2,
+                YouTubeSort::ViewCount => 3,
+            };
+            message.extend([0x08, value]);
+        }
+        if !filters.is_empty() {
+            message.extend([0x12, filters.len() as u8]);
+            message.extend(filters);
+        }
+
+        (!message.is_empty()).then(|| base64_encode(&message))
+    }
+}
+
+impl EngineQuery for YouTubeQuery {
+    type Response = YouTubeResults;
+
+    fn engine(&self) -> &str {
+        "youtube"
+    }
+
+    fn params(&self) -> SerpResult<Vec<(String, String)>> {
+        let encoded = serde_urlencoded::to_string(self)?;
+        let mut params: Vec<(String, String)> = url::form_urlencoded::parse(encoded.as_bytes())
+            .into_owned()
+            .collect();
+        if let Some(sp) = self.sp() {
+            params.push(("sp".to_string(), sp));
+        }
+        Ok(params)
+    }
+
+    fn validate(&self) -> SerpResult<()> {
+        if self.search_query.trim().is_empty() {
+            return Err(SerpError::InvalidParameter(
+                "YouTube search requires a search_query".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn next_page(&self, response: &YouTubeResults) -> Option<Self> {
+        response
+            .serpapi_pagination
+            .as_ref()
+            .and_then(|p| p.next_page_token.clone())
+            .map(|token| self.clone().raw_sp(token))
+    }
+}
+
+/// Results of a `youtube` search
+#[derive(Debug, Deserialize, Clone)]
+pub struct YouTubeResults {
+    pub search_metadata: SearchMetadata,
+    pub search_parameters: Option<EngineParameters>,
+    pub video_results: Option<Vec<YouTubeVideo>>,
+    pub channel_results: Option<Vec<YouTubeChannel>>,
+    pub playlist_results: Option<Vec<YouTubePlaylist>>,
+    pub shorts_results: Option<Vec<YouTubeShortsBlock>>,
+    pub ads_results: Option<Vec<YouTubeAd>>,
+    pub movie_results: Option<Vec<YouTubeVideo>>,
+    pub serpapi_pagination: Option<SerpapiPagination>,
+}
+
+/// Channel reference attached to videos and playlists
+#[derive(Debug, Deserialize, Clone)]
+pub struct ChannelInfo {
+    pub name: Option<String>,
+    pub link: Option<String>,
+    pub verified: Option<bool>,
+    pub thumbnail: Option<String>,
+}
+
+/// Static and animated thumbnails of a video
+#[derive(Debug, Deserialize, Clone)]
+pub struct VideoThumbnail {
+    #[serde(rename = "static")]
+    pub static_image: Option<String>,
+    pub rich: Option<String>,
+}
+
+/// YouTube video result
+#[derive(Debug, Deserialize, Clone)]
+pub struct YouTubeVideo {
+    pub position_on_page: Option<u32>,
+    pub title: String,
+    pub link: String,
+    pub serpapi_link: Option<String>,
+    pub channel: Option<ChannelInfo>,
+    pub published_date: Option<String>,
+    #[serde(default, deserialize_with = "parse::deserialize_count")]
+    pub views: Option<u64>,
+    pub length: Option<String>,
+    pub description: Option<String>,
+    pub extensions: Option<Vec<String>>,
+    pub thumbnail: Option<VideoThumbnail>,
+}
+
+impl YouTubeVideo {
+    /// Age of the video parsed from `published_date` (e.g. "3 weeks ago")
+    pub fn published_age(&self) -> Option<Duration> {
+        self.published_date
+            .as_deref()
+            .and_then(parse::parse_relative_age)
+    }
+
+    /// Video length parsed from `length` (e.g. "1:02:03")
+    pub fn duration(&self) -> Option<Duration> {
+        let mut seconds = 0u64;
+        for part in self.length.as_deref()?.split(':') {
+            seconds = seconds * 60 + part.trim().parse::<u64>().ok()?;
+        }
+        Some(Duration::from_secs(seconds))
+    }
+}
+
+/// YouTube channel result
+#[derive(Debug, Deserialize, Clone)]
+pub struct YouTubeChannel {
+    pub position_on_page: Option<u32>,
+    pub title: String,
+    pub link: String,
+    pub verified: Option<bool>,
+    pub handle: Option<String>,
+    #[serde(default, deserialize_with = "parse::deserialize_count")]
+    pub subscribers: Option<u64>,
+    pub description: Option<String>,
+    pub thumbnail: Option<String>,
+}
+
+/// YouTube playlist result
+#[derive(Debug, Deserialize, Clone)]
+pub struct YouTubePlaylist {
+    pub position_on_page: Option<u32>,
+    pub title: String,
+    pub link: String,
+    pub channel: Option<ChannelInfo>,
+    #[serde(default, deserialize_with = "parse::deserialize_count")]
+    pub video_count: Option<u64>,
+    pub videos: Option<Vec<PlaylistVideo>>,
+    pub thumbnail: Option<String>,
+}
+
+/// Video listed in a playlist result
+#[derive(Debug, Deserialize, Clone)]
+pub struct PlaylistVideo {
+    pub title: Option<String>,
+    pub link: Option<String>,
+    pub length: Option<String>,
+}
+
+/// Block of YouTube Shorts
+#[derive(Debug, Deserialize, Clone)]
+pub struct YouTubeShortsBlock {
+    pub position_on_page: Option<u32>,
+    pub shorts: Vec<YouTubeShort>,
+}
+
+/// YouTube Short
+#[derive(Debug, Deserialize, Clone)]
+pub struct YouTubeShort {
+    pub title: Option<String>,
+    pub link: Option<String>,
+    pub thumbnail: Option<String>,
+    pub video_id: Option<String>,
+    pub views_original: Option<String>,
+    #[serde(default, deserialize_with = "parse::deserialize_count")]
+    pub views: Option<u64>,
+}
+
+/// YouTube ad result
+#[derive(Debug, Deserialize, Clone)]
+pub struct YouTubeAd {
+    pub position_on_page: Option<u32>,
+    pub title: Option<String>,
+    pub link: Option<String>,
+    pub channel: Option<ChannelInfo>,
+    pub description: Option<String>,
+    pub thumbnail: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sp_encoding() {
+        assert_eq!(
+            YouTubeQuery::new("rust")
+                .result_type(YouTubeResultType::Video)
+                .sp()
+                .as_deref(),
+            Some("EgIQAQ==")
+        );
+        assert_eq!(
+            YouTubeQuery::new("rust")
+                .upload_date(UploadDate::LastHour)
+                .sp()
+                .as_deref(),
+            Some("EgIIAQ==")
+        );
+        assert_eq!(
+            YouTubeQuery::new("rust")
+                .sort_by(YouTubeSort::UploadDate)
+                .sp()
+                .as_deref(),
+            Some("CAI=")
+        );
+        assert_eq!(YouTubeQuery::new("rust").sp(), None);
+    }
+
+    #[test]
+    fn test_youtube_results_parsing() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "video_results": [{
+                "position_on_page": 1,
+                "title": "Async Rust",
+                "link": "https://www.youtube.com/watch?v=1",
+                "channel": {"name": "Rustacean", "verified": true},
+                "published_date": "2 weeks ago",
+                "views": 15234,
+                "length": "1:02:03"
+            }],
+            "shorts_results": [{
+                "position_on_page": 2,
+                "shorts": [{"title": "Rust in 60s", "views_original": "1.2M views", "views": "1.2M views"}]
+            }],
+            "serpapi_pagination": {"next_page_token": "NEXT"}
+        }"#;
+        let results: YouTubeResults = serde_json::from_str(json).unwrap();
+
+        let video = &results.video_results.as_ref().unwrap()[0];
+        assert_eq!(video.views, Some(15234));
+        assert_eq!(
+            video.published_age(),
+            Some(Duration::from_secs(14 * 86_400))
+        );
+        assert_eq!(video.duration(), Some(Duration::from_secs(3723)));
+        assert_eq!(
+            results.shorts_results.as_ref().unwrap()[0].shorts[0].views,
+            Some(1_200_000)
+        );
+
+        let next = YouTubeQuery::new("rust")
+            .upload_date(UploadDate::Today)
+            .next_page(&results)
+            .unwrap();
+        assert_eq!(next.sp().as_deref(), Some("NEXT"));
+    }
+}
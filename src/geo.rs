@@ -0,0 +1,144 @@
+use std::fmt;
+
+use crate::encoding::base64_encode;
+use crate::error::{SerpError, SerpResult};
+
+/// Alphabet used for the length key of a canonical-name UULE.
+const UULE_KEY_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// A validated latitude/longitude pair.
+///
+/// Used to target searches at a precise position, either through an encoded
+/// `uule` parameter or through the `ll` parameter of the Google Maps engine.
+///
+/// # Examples
+///
+/// ```rust
+/// use serp_sdk::geo::GeoPoint;
+///
+/// let point = GeoPoint::new(40.7455096, -74.0083012)?;
+/// assert_eq!(point.ll(14)?, "@40.7455096,-74.0083012,14z");
+/// # Ok::<(), serp_sdk::SerpError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoPoint {
+    latitude: f64,
+    longitude: f64,
+}
+
+impl GeoPoint {
+    /// Create a point, validating latitude (-90..=90) and longitude (-180..=180)
+    pub fn new(latitude: f64, longitude: f64) -> SerpResult<Self> {
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(SerpError::InvalidParameter(
+                "latitude must be between -90 and 90".to_string(),
+            ));
+        }
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(SerpError::InvalidParameter(
+                "longitude must be between -180 and 180".to_string(),
+            ));
+        }
+        Ok(Self {
+            latitude,
+            longitude,
+        })
+    }
+
+    /// Get the latitude
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// Get the longitude
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Format the point as a SerpAPI `ll` value with a zoom level (3-21)
+    pub fn ll(&self, zoom: u8) -> SerpResult<String> {
+        if !(3..=21).contains(&zoom) {
+            return Err(SerpError::InvalidParameter(
+                "zoom must be between 3 and 21".to_string(),
+            ));
+        }
+        Ok(format!("@{},{},{}z", self.latitude, self.longitude, zoom))
+    }
+
+    /// Encode the point as a coordinate-based `uule` value
+    pub fn uule(&self) -> String {
+        let latitude_e7 = (self.latitude * 1e7).round() as i64;
+        let longitude_e7 = (self.longitude * 1e7).round() as i64;
+        let descriptor = format!(
+            "role: CURRENT_LOCATION\nproducer: DEVICE_LOCATION\nradius: 65000\nlatlng <\n  latitude_e7: {}\n  longitude_e7: {}\n>",
+            latitude_e7, longitude_e7
+        );
+        format!("a+{}", base64_encode(descriptor.as_bytes()))
+    }
+}
+
+impl fmt::Display for GeoPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.latitude, self.longitude)
+    }
+}
+
+/// Encode a canonical location name as a `uule` value
+///
+/// Canonical names are the comma-separated names used by Google Ads geotargets,
+/// e.g. "Austin,Texas,United States". Names longer than 63 bytes are rejected.
+///
+/// # Examples
+///
+/// ```rust
+/// use serp_sdk::geo::encode_uule;
+///
+/// let uule = encode_uule("Austin,Texas,United States")?;
+/// assert!(uule.starts_with("w+CAIQICI"));
+/// # Ok::<(), serp_sdk::SerpError>(())
+/// ```
+pub fn encode_uule(canonical_name: &str) -> SerpResult<String> {
+    let name = canonical_name.trim();
+    if name.is_empty() {
+        return Err(SerpError::InvalidParameter(
+            "canonical location name cannot be empty".to_string(),
+        ));
+    }
+    let key = UULE_KEY_ALPHABET.get(name.len()).ok_or_else(|| {
+        SerpError::InvalidParameter("canonical location name must be at most 63 bytes".to_string())
+    })?;
+
+    Ok(format!(
+        "w+CAIQICI{}{}",
+        *key as char,
+        base64_encode(name.as_bytes())
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_uule() {
+        assert_eq!(
+            encode_uule("Austin,Texas,United States").unwrap(),
+            "w+CAIQICIaQXVzdGluLFRleGFzLFVuaXRlZCBTdGF0ZXM="
+        );
+        assert!(encode_uule("").is_err());
+        assert!(encode_uule(&"x".repeat(64)).is_err());
+    }
+
+    #[test]
+    fn test_geo_point_validation() {
+        assert!(GeoPoint::new(91.0, 0.0).is_err());
+        assert!(GeoPoint::new(0.0, -181.0).is_err());
+
+        let point = GeoPoint::new(30.2672, -97.7431).unwrap();
+        assert_eq!(point.ll(15).unwrap(), "@30.2672,-97.7431,15z");
+        assert!(point.ll(2).is_err());
+        assert!(point.ll(22).is_err());
+        assert!(point.uule().starts_with("a+cm9sZTog"));
+    }
+}
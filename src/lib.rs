@@ -169,7 +169,7 @@
 //!     SearchQuery::new("rust programming language")
 //!         .news()    // Automatically sets tbm=nws parameter
 //!         .language("en")
-//!         .time_filter("d")  // Last 24 hours
+//!         .param("tbs", "qdr:d")?  // Last 24 hours
 //! ).await?;
 //!
 //! // Video search results
@@ -209,12 +209,11 @@
 //!     .country("us")
 //!     .device("desktop")      // Desktop, tablet, or mobile
 //!     .safe_search("off")     // off, active, or medium
-//!     .time_filter("m")       // Past month
-//!     .filter("0")           // Include similar results
 //!     .offset(10)            // Start from result 10
 //!     .limit(50)?            // Get 50 results
-//!     .custom_param("gl", "us")  // Add any SerpAPI parameter
-//!     .custom_param("lr", "lang_en");
+//!     .param("tbs", "qdr:m")?    // Past month
+//!     .param("filter", "0")?     // Include similar results
+//!     .param("lr", "lang_en")?;  // Add any SerpAPI parameter
 //!
 //! let results = client.search(complex_query).await?;
 //! # Ok(())
@@ -316,7 +315,7 @@
 //!
 //! // Access answer box
 //! if let Some(answer) = results.answer_box {
-//!     println!("Answer: {:?}", answer.answer);
+//!     println!("Answer ({}): {:?}", answer.answer_type(), answer.text());
 //! }
 //!
 //! // Access related searches
@@ -476,8 +475,11 @@
 //!
 //! - [`client`]: HTTP client implementation and configuration
 //! - [`query`]: Query builder and search parameters
+//! - [`operators`]: Search operator expressions for the `q` parameter
+//! - [`geo`]: UULE encoding and coordinate-based targeting
 //! - [`response`]: Response structures and deserialization
 //! - [`streaming`]: Async streaming support for pagination
+//! - [`engines`]: Dedicated engines such as Google Maps, YouTube, Google Scholar, Bing and regional engines like Yandex and Baidu
 //! - [`error`]: Error types and handling
 //! - [`retry`]: Retry policies and backoff strategies
 
@@ -504,6 +506,13 @@
 /// ```
 pub mod client;
 
+/// Typed queries and responses for SerpAPI's dedicated search engines.
+///
+/// Each submodule covers one engine family (Google Maps, YouTube, ...). Engine
+/// queries implement [`EngineQuery`](engines::EngineQuery) and are executed with
+/// [`SerpClient::search_engine`](client::SerpClient::search_engine).
+pub mod engines;
+
 /// Comprehensive error types for all SDK operations.
 ///
 /// This module defines the [`SerpError`] enum and related types that
@@ -517,6 +526,22 @@ pub mod error;
 /// for constructing complex search queries with compile-time validation where possible.
 pub mod query;
 
+mod encoding;
+mod parse;
+
+/// Geographic targeting helpers.
+///
+/// Provides [`GeoPoint`](geo::GeoPoint) and UULE encoding for precise location
+/// targeting through the `uule` and `ll` parameters.
+pub mod geo;
+
+/// Composable search operator expressions.
+///
+/// The [`QueryExpr`](operators::QueryExpr) type builds and parses Google search operator
+/// syntax (`site:`, `filetype:`, exact phrases, exclusions, `OR` groups, ...) with
+/// correct quoting, for use as the `q` parameter of a [`SearchQuery`](query::SearchQuery).
+pub mod operators;
+
 /// Strongly-typed response structures for SerpAPI results.
 ///
 /// This module contains all the response types returned by SerpAPI, including organic results,
@@ -537,7 +562,9 @@ pub mod streaming;
 
 // Re-export main types for convenience
 pub use client::{SerpClient, SerpClientBuilder};
+pub use engines::EngineQuery;
 pub use error::{SerpError, SerpResult};
+pub use operators::{QueryExpr, QueryTerm};
 pub use query::{SearchQuery, SearchQueryBuilder};
 pub use response::SearchResults;
 pub use retry::RetryPolicy;
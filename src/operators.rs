@@ -0,0 +1,586 @@
+use std::fmt;
+use std::ops;
+use std::str::FromStr;
+
+use crate::error::{SerpError, SerpResult};
+
+/// A single element of a search expression.
+///
+/// Terms are rendered into Google's search operator syntax by [`QueryExpr`].
+/// Values that contain whitespace or characters with special meaning are quoted
+/// automatically, so callers never have to escape anything by hand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryTerm {
+    /// A plain keyword, e.g. `tokio`
+    Word(String),
+    /// An exact phrase, rendered as `"..."`
+    Phrase(String),
+    /// Restrict results to a domain (`site:`)
+    Site(String),
+    /// Restrict results to a file type (`filetype:`)
+    FileType(String),
+    /// Require a word or phrase in the page title (`intitle:`)
+    InTitle(String),
+    /// Require a word or phrase in the URL (`inurl:`)
+    InUrl(String),
+    /// Only results published before a date (`before:YYYY-MM-DD`)
+    Before(String),
+    /// Only results published after a date (`after:YYYY-MM-DD`)
+    After(String),
+    /// Pages related to a domain (`related:`)
+    Related(String),
+    /// Any of the alternatives, rendered as `(a OR b)`
+    Or(Vec<QueryTerm>),
+    /// A parenthesized group of terms that must all match
+    Group(Vec<QueryTerm>),
+    /// Exclude the inner term, rendered with a leading `-`
+    Not(Box<QueryTerm>),
+    /// Text passed through without any escaping
+    Raw(String),
+}
+
+impl QueryTerm {
+    /// Create a keyword term.
+    ///
+    /// A keyword containing whitespace or operator syntax such as `:` is
+    /// rendered as a quoted phrase.
+    pub fn word(word: impl Into<String>) -> Self {
+        QueryTerm::Word(word.into())
+    }
+
+    /// Create an exact phrase term
+    pub fn phrase(phrase: impl Into<String>) -> Self {
+        QueryTerm::Phrase(phrase.into())
+    }
+
+    /// Create a `site:` term
+    pub fn site(domain: impl Into<String>) -> Self {
+        QueryTerm::Site(domain.into())
+    }
+
+    fn render(&self, out: &mut String) {
+        match self {
+            QueryTerm::Word(word) => out.push_str(&escape_value(word)),
+            QueryTerm::Phrase(phrase) => out.push_str(&quote(phrase)),
+            QueryTerm::Site(value) => render_operator(out, "site", value),
+            QueryTerm::FileType(value) => render_operator(out, "filetype", value),
+            QueryTerm::InTitle(value) => render_operator(out, "intitle", value),
+            QueryTerm::InUrl(value) => render_operator(out, "inurl", value),
+            QueryTerm::Before(value) => render_operator(out, "before", value),
+            QueryTerm::After(value) => render_operator(out, "after", value),
+            QueryTerm::Related(value) => render_operator(out, "related", value),
+            QueryTerm::Or(alternatives) => render_group(out, alternatives, " OR "),
+            QueryTerm::Group(terms) => render_group(out, terms, " "),
+            QueryTerm::Not(inner) => {
+                out.push('-');
+                inner.render(out);
+            }
+            QueryTerm::Raw(text) => out.push_str(text),
+        }
+    }
+}
+
+impl ops::Not for QueryTerm {
+    type Output = QueryTerm;
+
+    fn not(self) -> Self::Output {
+        QueryTerm::Not(Box::new(self))
+    }
+}
+
+impl fmt::Display for QueryTerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = String::new();
+        self.render(&mut out);
+        f.write_str(&out)
+    }
+}
+
+/// Composable search expression built from Google search operators.
+///
+/// `QueryExpr` renders a correctly quoted `q` parameter and can parse an
+/// existing query string back into its terms.
+///
+/// # Examples
+///
+/// ```rust
+/// use serp_sdk::{QueryExpr, SearchQuery};
+///
+/// let expr = QueryExpr::new()
+///     .site("docs.rs")
+///     .phrase("tokio")
+///     .exclude("actix")
+///     .filetype("pdf")
+///     .after("2024-01-01");
+///
+/// assert_eq!(
+///     expr.to_string(),
+///     r#"site:docs.rs "tokio" -actix filetype:pdf after:2024-01-01"#
+/// );
+///
+/// // Expressions can be passed straight to the query builder
+/// let query = SearchQuery::new(expr);
+///
+/// // ...and parsed back from an existing query string
+/// let parsed: QueryExpr = r#"site:docs.rs "tokio" -actix"#.parse()?;
+/// assert_eq!(parsed.terms().len(), 3);
+/// # Ok::<(), serp_sdk::SerpError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryExpr {
+    terms: Vec<QueryTerm>,
+}
+
+impl QueryExpr {
+    /// Create an empty expression
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a query string into an expression
+    ///
+    /// Unknown operators (e.g. `intext:`) are kept verbatim so that rendering
+    /// the parsed expression reproduces the original query.
+    pub fn parse(query: &str) -> SerpResult<Self> {
+        let tokens = tokenize(query)?;
+        let mut pos = 0;
+        let terms = parse_sequence(&tokens, &mut pos, false)?;
+        Ok(Self { terms })
+    }
+
+    /// Get the terms of this expression
+    pub fn terms(&self) -> &[QueryTerm] {
+        &self.terms
+    }
+
+    /// Check whether the expression has no terms
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Append an arbitrary term
+    pub fn push(mut self, term: QueryTerm) -> Self {
+        self.terms.push(term);
+        self
+    }
+
+    /// Add a keyword
+    pub fn term(self, word: impl Into<String>) -> Self {
+        self.push(QueryTerm::Word(word.into()))
+    }
+
+    /// Add an exact phrase (`"..."`)
+    pub fn phrase(self, phrase: impl Into<String>) -> Self {
+        self.push(QueryTerm::Phrase(phrase.into()))
+    }
+
+    /// Exclude a keyword or phrase (`-term`)
+    pub fn exclude(self, word: impl Into<String>) -> Self {
+        self.push(!QueryTerm::Word(word.into()))
+    }
+
+    /// Restrict results to a domain (`site:`)
+    pub fn site(self, domain: impl Into<String>) -> Self {
+        self.push(QueryTerm::Site(domain.into()))
+    }
+
+    /// Exclude a domain from the results (`-site:`)
+    pub fn exclude_site(self, domain: impl Into<String>) -> Self {
+        self.push(!QueryTerm::Site(domain.into()))
+    }
+
+    /// Restrict results to a file type (`filetype:`)
+    /// Examples: "pdf", "docx", "xls"
+    pub fn filetype(self, extension: impl Into<String>) -> Self {
+        self.push(QueryTerm::FileType(extension.into()))
+    }
+
+    /// Require a word or phrase in the page title (`intitle:`)
+    pub fn intitle(self, text: impl Into<String>) -> Self {
+        self.push(QueryTerm::InTitle(text.into()))
+    }
+
+    /// Require a word or phrase in the URL (`inurl:`)
+    pub fn inurl(self, text: impl Into<String>) -> Self {
+        self.push(QueryTerm::InUrl(text.into()))
+    }
+
+    /// Only include results published before a date (`before:`)
+    /// Format: "YYYY-MM-DD" or "YYYY"
+    pub fn before(self, date: impl Into<String>) -> Self {
+        self.push(QueryTerm::Before(date.into()))
+    }
+
+    /// Only include results published after a date (`after:`)
+    /// Format: "YYYY-MM-DD" or "YYYY"
+    pub fn after(self, date: impl Into<String>) -> Self {
+        self.push(QueryTerm::After(date.into()))
+    }
+
+    /// Find pages related to a domain (`related:`)
+    pub fn related(self, domain: impl Into<String>) -> Self {
+        self.push(QueryTerm::Related(domain.into()))
+    }
+
+    /// Match any of the given terms (`(a OR b)`)
+    pub fn any_of(self, alternatives: impl IntoIterator<Item = QueryTerm>) -> Self {
+        let alternatives: Vec<QueryTerm> = alternatives.into_iter().collect();
+        match alternatives.len() {
+            0 => self,
+            1 => self.push(alternatives.into_iter().next().unwrap()),
+            _ => self.push(QueryTerm::Or(alternatives)),
+        }
+    }
+}
+
+impl fmt::Display for QueryExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = String::new();
+        for (i, term) in self.terms.iter().enumerate() {
+            if i > 0 {
+                out.push(' ');
+            }
+            term.render(&mut out);
+        }
+        f.write_str(&out)
+    }
+}
+
+impl FromStr for QueryExpr {
+    type Err = SerpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl From<QueryExpr> for String {
+    fn from(expr: QueryExpr) -> Self {
+        expr.to_string()
+    }
+}
+
+impl From<QueryTerm> for QueryExpr {
+    fn from(term: QueryTerm) -> Self {
+        Self { terms: vec![term] }
+    }
+}
+
+fn render_operator(out: &mut String, operator: &str, value: &str) {
+    out.push_str(operator);
+    out.push(':');
+    out.push_str(&escape_value(value));
+}
+
+fn render_group(out: &mut String, terms: &[QueryTerm], separator: &str) {
+    out.push('(');
+    for (i, term) in terms.iter().enumerate() {
+        if i > 0 {
+            out.push_str(separator);
+        }
+        term.render(out);
+    }
+    out.push(')');
+}
+
+/// Quote a value if it would otherwise be interpreted as operator syntax.
+fn escape_value(value: &str) -> String {
+    let needs_quotes = value.is_empty()
+        || value == "OR"
+        || value.starts_with('-')
+        || value
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '"' | '(' | ')' | '|' | ':'));
+
+    if needs_quotes {
+        quote(value)
+    } else {
+        value.to_string()
+    }
+}
+
+/// Google has no escape sequence for quotes inside a phrase, so they are dropped.
+fn quote(value: &str) -> String {
+    let inner: String = value.chars().filter(|&c| c != '"').collect();
+    format!("\"{}\"", inner.trim())
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    Or,
+    Not,
+    Atom {
+        operator: Option<String>,
+        value: String,
+        quoted: bool,
+    },
+}
+
+fn tokenize(input: &str) -> SerpResult<Vec<Token>> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token::Open);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::Close);
+                i += 1;
+            }
+            '|' => {
+                tokens.push(Token::Or);
+                i += 1;
+            }
+            '-' if chars.get(i + 1).is_some_and(|n| !n.is_whitespace()) => {
+                tokens.push(Token::Not);
+                i += 1;
+            }
+            '"' => {
+                let (value, next) = read_quoted(&chars, i)?;
+                tokens.push(Token::Atom {
+                    operator: None,
+                    value,
+                    quoted: true,
+                });
+                i = next;
+            }
+            _ => {
+                let start = i;
+                while i < chars.len() && !chars[i].is_whitespace() && !matches!(chars[i], '(' | ')')
+                {
+                    // `intitle:"rust book"` - the quoted value belongs to the operator
+                    if chars[i] == ':' && chars.get(i + 1) == Some(&'"') {
+                        break;
+                    }
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+
+                if i < chars.len() && chars[i] == ':' {
+                    let (value, next) = read_quoted(&chars, i + 1)?;
+                    tokens.push(Token::Atom {
+                        operator: Some(word),
+                        value,
+                        quoted: true,
+                    });
+                    i = next;
+                } else if word == "OR" {
+                    tokens.push(Token::Or);
+                } else if let Some((operator, value)) = word
+                    .split_once(':')
+                    .filter(|(operator, value)| !operator.is_empty() && !value.is_empty())
+                {
+                    tokens.push(Token::Atom {
+                        operator: Some(operator.to_string()),
+                        value: value.to_string(),
+                        quoted: false,
+                    });
+                } else {
+                    tokens.push(Token::Atom {
+                        operator: None,
+                        value: word,
+                        quoted: false,
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Read a `"..."` segment starting at `start`, returning its content and the next index.
+fn read_quoted(chars: &[char], start: usize) -> SerpResult<(String, usize)> {
+    let end = chars[start + 1..]
+        .iter()
+        .position(|&c| c == '"')
+        .map(|offset| start + 1 + offset)
+        .ok_or_else(|| SerpError::InvalidParameter("unterminated quote in query".to_string()))?;
+    Ok((chars[start + 1..end].iter().collect(), end + 1))
+}
+
+fn parse_sequence(tokens: &[Token], pos: &mut usize, nested: bool) -> SerpResult<Vec<QueryTerm>> {
+    let mut terms: Vec<QueryTerm> = Vec::new();
+    let mut pending_or = false;
+
+    while *pos < tokens.len() {
+        if tokens[*pos] == Token::Close {
+            if !nested {
+                return Err(SerpError::InvalidParameter(
+                    "unbalanced ')' in query".to_string(),
+                ));
+            }
+            break;
+        }
+
+        if tokens[*pos] == Token::Or {
+            if terms.is_empty() || pending_or {
+                return Err(SerpError::InvalidParameter(
+                    "OR must appear between two terms".to_string(),
+                ));
+            }
+            pending_or = true;
+            *pos += 1;
+            continue;
+        }
+
+        let term = parse_unary(tokens, pos)?;
+        if pending_or {
+            pending_or = false;
+            match terms.pop() {
+                Some(QueryTerm::Or(mut alternatives)) => {
+                    alternatives.push(term);
+                    terms.push(QueryTerm::Or(alternatives));
+                }
+                Some(previous) => terms.push(QueryTerm::Or(vec![previous, term])),
+                None => unreachable!("OR without a preceding term is rejected above"),
+            }
+        } else {
+            terms.push(term);
+        }
+    }
+
+    if pending_or {
+        return Err(SerpError::InvalidParameter(
+            "OR must appear between two terms".to_string(),
+        ));
+    }
+
+    Ok(terms)
+}
+
+fn parse_unary(tokens: &[Token], pos: &mut usize) -> SerpResult<QueryTerm> {
+    let token = &tokens[*pos];
+    *pos += 1;
+
+    match token {
+        Token::Not => {
+            if *pos >= tokens.len() || matches!(tokens[*pos], Token::Close | Token::Or) {
+                return Err(SerpError::InvalidParameter(
+                    "'-' must be followed by a term".to_string(),
+                ));
+            }
+            Ok(!parse_unary(tokens, pos)?)
+        }
+        Token::Open => {
+            let mut inner = parse_sequence(tokens, pos, true)?;
+            if *pos >= tokens.len() {
+                return Err(SerpError::InvalidParameter(
+                    "unbalanced '(' in query".to_string(),
+                ));
+            }
+            *pos += 1;
+            match inner.len() {
+                1 => Ok(inner.remove(0)),
+                _ => Ok(QueryTerm::Group(inner)),
+            }
+        }
+        Token::Atom {
+            operator,
+            value,
+            quoted,
+        } => Ok(match operator {
+            None if *quoted => QueryTerm::Phrase(value.clone()),
+            None => QueryTerm::Word(value.clone()),
+            Some(operator) => match operator.to_ascii_lowercase().as_str() {
+                "site" => QueryTerm::Site(value.clone()),
+                "filetype" => QueryTerm::FileType(value.clone()),
+                "intitle" => QueryTerm::InTitle(value.clone()),
+                "inurl" => QueryTerm::InUrl(value.clone()),
+                "before" => QueryTerm::Before(value.clone()),
+                "after" => QueryTerm::After(value.clone()),
+                "related" => QueryTerm::Related(value.clone()),
+                // Unknown operator, keep it verbatim
+                _ if *quoted => QueryTerm::Raw(format!("{}:\"{}\"", operator, value)),
+                _ => QueryTerm::Raw(format!("{}:{}", operator, value)),
+            },
+        }),
+        Token::Close | Token::Or => unreachable!("handled by parse_sequence"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_operators() {
+        let expr = QueryExpr::new()
+            .site("docs.rs")
+            .phrase("tokio")
+            .exclude("actix")
+            .filetype("pdf")
+            .after("2024-01-01");
+
+        assert_eq!(
+            expr.to_string(),
+            r#"site:docs.rs "tokio" -actix filetype:pdf after:2024-01-01"#
+        );
+    }
+
+    #[test]
+    fn test_render_escaping() {
+        let expr = QueryExpr::new()
+            .intitle("rust book")
+            .phrase(r#"say "hi""#)
+            .exclude("async std")
+            .term("-flag")
+            .any_of([QueryTerm::site("github.com"), QueryTerm::site("gitlab.com")]);
+
+        assert_eq!(
+            expr.to_string(),
+            r#"intitle:"rust book" "say hi" -"async std" "-flag" (site:github.com OR site:gitlab.com)"#
+        );
+
+        // Operator syntax inside a value stays a literal term
+        let expr = QueryExpr::new().term("site:evil.com").intitle("intitle:x");
+        assert_eq!(expr.to_string(), r#""site:evil.com" intitle:"intitle:x""#);
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let query = r#"site:docs.rs "tokio runtime" -actix -site:reddit.com (serde OR "serde json") intitle:"rust book" before:2025"#;
+        let expr = QueryExpr::parse(query).unwrap();
+
+        assert_eq!(
+            expr.terms(),
+            &[
+                QueryTerm::Site("docs.rs".into()),
+                QueryTerm::Phrase("tokio runtime".into()),
+                !QueryTerm::Word("actix".into()),
+                !QueryTerm::Site("reddit.com".into()),
+                QueryTerm::Or(vec![
+                    QueryTerm::Word("serde".into()),
+                    QueryTerm::Phrase("serde json".into()),
+                ]),
+                QueryTerm::InTitle("rust book".into()),
+                QueryTerm::Before("2025".into()),
+            ]
+        );
+        assert_eq!(expr.to_string(), query);
+        assert_eq!(QueryExpr::parse(&expr.to_string()).unwrap(), expr);
+    }
+
+    #[test]
+    fn test_parse_unknown_operators_kept_verbatim() {
+        let query = r#"intext:rust AllInTitle:"async io" a-b 10:30"#;
+        assert_eq!(QueryExpr::parse(query).unwrap().to_string(), query);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(QueryExpr::parse(r#""unterminated"#).is_err());
+        assert!(QueryExpr::parse("(a OR b").is_err());
+        assert!(QueryExpr::parse("a OR b)").is_err());
+        assert!(QueryExpr::parse("OR rust").is_err());
+        assert!(QueryExpr::parse("rust OR").is_err());
+    }
+}
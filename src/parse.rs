@@ -0,0 +1,337 @@
+//! Lenient parsing of the human-readable values SerpAPI passes through from
+//! result pages ("1.2M views", "3 days ago", ...).
+
+use serde::{Deserialize, Deserializer};
+use std::time::Duration;
+
+/// Parse a count such as "1,234", "1.2M views" or "15K"
+pub(crate) fn parse_count(text: &str) -> Option<u64> {
+    let text = text.trim();
+    if text.to_ascii_lowercase().starts_with("no ") {
+        return Some(0);
+    }
+
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+    let number: String = text[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == ',' || *c == '.')
+        .filter(|c| *c != ',')
+        .collect();
+    // A multiplier follows the number directly ("1.2M views") or is a word of
+    // its own ("1.2 M"); the first letter of a following word ("100 bought") is not
+    let rest =
+        text[start..].trim_start_matches(|c: char| c.is_ascii_digit() || c == ',' || c == '.');
+    let suffix = match rest.chars().next() {
+        Some(c) if c.is_whitespace() => rest.split_whitespace().next().filter(|w| w.len() == 1),
+        Some(_) => Some(rest),
+        None => None,
+    }
+    .and_then(|word| {
+        let mut chars = word.chars();
+        let letter = chars.next()?;
+        chars
+            .next()
+            .map_or(true, |c| !c.is_alphabetic())
+            .then_some(letter)
+    });
+
+    let multiplier = match suffix.map(|c| c.to_ascii_uppercase()) {
+        Some('K') => 1e3,
+        Some('M') => 1e6,
+        Some('B') => 1e9,
+        _ => 1.0,
+    };
+    let value: f64 = number.trim_end_matches('.').parse().ok()?;
+    Some((value * multiplier).round() as u64)
+}
+
+/// Parse a signed decimal such as "$181.23", "-0.53%", "94.93B" or "2.78T USD"
+pub(crate) fn parse_decimal(text: &str) -> Option<f64> {
+    let text = text.trim();
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+    let negative = text[..start].contains(['-', '\u{2212}']);
+    let digits = &text[start..];
+    let end = digits
+        .find(|c: char| !(c.is_ascii_digit() || c == ',' || c == '.'))
+        .unwrap_or(digits.len());
+    let number = digits[..end].replace(',', "");
+    let suffix = digits[end..].trim_start().chars().next();
+
+    let multiplier = match suffix {
+        Some('K') => 1e3,
+        Some('M') => 1e6,
+        Some('B') => 1e9,
+        Some('T') => 1e12,
+        _ => 1.0,
+    };
+    let value: f64 = number.trim_end_matches('.').parse().ok()?;
+    let value = value * multiplier;
+    Some(if negative { -value } else { value })
+}
+
+/// Parse a relative age such as "3 days ago" or "Streamed 1 year ago"
+pub(crate) fn parse_relative_age(text: &str) -> Option<Duration> {
+    const HOUR: u64 = 60 * 60;
+    const DAY: u64 = 24 * HOUR;
+
+    let words: Vec<String> = text
+        .split_whitespace()
+        .map(|w| w.to_ascii_lowercase())
+        .collect();
+    let position = words.iter().position(|w| w.parse::<u64>().is_ok())?;
+    let amount: u64 = words[position].parse().ok()?;
+    let unit = words.get(position + 1)?.trim_end_matches('s');
+
+    let seconds = match unit {
+        "second" | "sec" => 1,
+        "minute" | "min" => 60,
+        "hour" | "hr" => HOUR,
+        "day" => DAY,
+        "week" => 7 * DAY,
+        "month" => 30 * DAY,
+        "year" => 365 * DAY,
+        _ => return None,
+    };
+    Some(Duration::from_secs(amount * seconds))
+}
+
+/// Currency symbols and prefixes, longest first so "C$" wins over "$"
+const CURRENCY_SYMBOLS: &[(&str, &str)] = &[
+    ("CA$", "CAD"),
+    ("AU$", "AUD"),
+    ("US$", "USD"),
+    ("NZ$", "NZD"),
+    ("HK$", "HKD"),
+    ("MX$", "MXN"),
+    ("NT$", "TWD"),
+    ("R$", "BRL"),
+    ("S$", "SGD"),
+    ("C$", "CAD"),
+    ("A$", "AUD"),
+    ("$", "USD"),
+    ("€", "EUR"),
+    ("£", "GBP"),
+    ("¥", "JPY"),
+    ("₹", "INR"),
+    ("₩", "KRW"),
+    ("₽", "RUB"),
+    ("₺", "TRY"),
+    ("zł", "PLN"),
+];
+
+/// Parse a displayed price such as "$1,299.99", "1.299,00 €" or "CHF 45"
+/// into an amount and an ISO 4217 currency code
+pub(crate) fn parse_price(text: &str) -> Option<(f64, Option<String>)> {
+    let text = text.trim();
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+    let number: String = text[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == ',' || *c == '.' || *c == '\u{a0}')
+        .filter(|c| *c != '\u{a0}')
+        .collect();
+    let number = number.trim_end_matches(['.', ',']);
+
+    // The last separator is decimal when followed by one or two digits
+    let normalized = match number.rfind(['.', ',']) {
+        Some(index) if (1..=2).contains(&(number.len() - index - 1)) => {
+            let (integer, fraction) = number.split_at(index);
+            format!("{}.{}", integer.replace(['.', ','], ""), &fraction[1..])
+        }
+        _ => number.replace(['.', ','], ""),
+    };
+    let amount: f64 = normalized.parse().ok()?;
+
+    // A symbol preceded by a letter is part of a prefix we don't know ("Z$")
+    let has_symbol = |symbol: &str| {
+        text.match_indices(symbol)
+            .any(|(index, _)| !text[..index].ends_with(|c: char| c.is_alphabetic()))
+    };
+    let currency = CURRENCY_SYMBOLS
+        .iter()
+        .find(|(symbol, _)| has_symbol(symbol))
+        .map(|(_, code)| code.to_string())
+        .or_else(|| {
+            text.split(|c: char| !c.is_ascii_alphabetic())
+                .find(|word| word.len() == 3 && word.chars().all(|c| c.is_ascii_uppercase()))
+                .map(str::to_string)
+        });
+
+    Some((amount, currency))
+}
+
+/// Convert a Google News date such as "07/17/2024, 07:00 AM, +0000 UTC" to
+/// ISO 8601 ("2024-07-17T07:00:00Z")
+pub(crate) fn parse_news_date(text: &str) -> Option<String> {
+    let mut parts = text.split(',').map(str::trim);
+    let date = parts.next()?;
+    let time = parts.next()?;
+    let offset = parts.next().unwrap_or("+0000");
+
+    let mut date_parts = date.split('/');
+    let month: u32 = date_parts.next()?.parse().ok()?;
+    let day: u32 = date_parts.next()?.parse().ok()?;
+    let year: u32 = date_parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let (clock, meridiem) = time.split_once(' ').unwrap_or((time, ""));
+    let (hour, minute) = clock.split_once(':')?;
+    let mut hour: u32 = hour.parse().ok()?;
+    let minute: u32 = minute.parse().ok()?;
+    match meridiem.to_ascii_uppercase().as_str() {
+        "AM" if hour == 12 => hour = 0,
+        "PM" if hour < 12 => hour += 12,
+        _ => {}
+    }
+    if hour > 23 || minute > 59 {
+        return None;
+    }
+
+    let offset = offset.split_whitespace().next()?;
+    let zone = match offset.trim_start_matches(['+', '-']) {
+        "0000" => "Z".to_string(),
+        digits if digits.len() == 4 && digits.chars().all(|c| c.is_ascii_digit()) => {
+            let sign = if offset.starts_with('-') { '-' } else { '+' };
+            format!("{}{}:{}", sign, &digits[..2], &digits[2..])
+        }
+        _ => return None,
+    };
+
+    Some(format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:00{}",
+        year, month, day, hour, minute, zone
+    ))
+}
+
+/// Check that a date is formatted as "YYYY-MM-DD"
+pub(crate) fn is_iso_date(text: &str) -> bool {
+    let parts: Vec<&str> = text.split('-').collect();
+    let [year, month, day] = parts.as_slice() else {
+        return false;
+    };
+    let digits =
+        |part: &str, len: usize| part.len() == len && part.chars().all(|c| c.is_ascii_digit());
+    digits(year, 4)
+        && digits(month, 2)
+        && digits(day, 2)
+        && (1..=12).contains(&month.parse::<u32>().unwrap_or(0))
+        && (1..=31).contains(&day.parse::<u32>().unwrap_or(0))
+}
+
+/// Deserialize a count that SerpAPI sends either as a number or as text
+pub(crate) fn deserialize_count<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(
+        match Option::<serde_json::Value>::deserialize(deserializer)? {
+            Some(serde_json::Value::Number(n)) => {
+                n.as_u64().or_else(|| n.as_f64().map(|f| f as u64))
+            }
+            Some(serde_json::Value::String(s)) => parse_count(&s),
+            _ => None,
+        },
+    )
+}
+
+/// Deserialize an integer that SerpAPI sends either as a number or as a numeric string
+pub(crate) fn deserialize_integer<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(
+        match Option::<serde_json::Value>::deserialize(deserializer)? {
+            Some(serde_json::Value::Number(n)) => n.as_u64(),
+            Some(serde_json::Value::String(s)) => s.trim().parse().ok(),
+            _ => None,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_count() {
+        assert_eq!(parse_count("1,234 views"), Some(1234));
+        assert_eq!(parse_count("1.2M views"), Some(1_200_000));
+        assert_eq!(parse_count("15K"), Some(15_000));
+        assert_eq!(parse_count("1.2 M"), Some(1_200_000));
+        assert_eq!(parse_count("100 bought"), Some(100));
+        assert_eq!(parse_count("500 members"), Some(500));
+        assert_eq!(parse_count("3 mil visualizaciones"), Some(3));
+        assert_eq!(parse_count("No views"), Some(0));
+        assert_eq!(parse_count("views"), None);
+    }
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(parse_decimal("$181.23"), Some(181.23));
+        assert_eq!(parse_decimal("-0.53%"), Some(-0.53));
+        assert_eq!(parse_decimal("94.93B"), Some(94.93e9));
+        assert_eq!(parse_decimal("2.78T USD"), Some(2.78e12));
+        assert_eq!(parse_decimal("—"), None);
+    }
+
+    #[test]
+    fn test_parse_relative_age() {
+        assert_eq!(
+            parse_relative_age("3 days ago"),
+            Some(Duration::from_secs(3 * 86_400))
+        );
+        assert_eq!(
+            parse_relative_age("Streamed 1 year ago"),
+            Some(Duration::from_secs(365 * 86_400))
+        );
+        assert_eq!(parse_relative_age("yesterday"), None);
+    }
+
+    #[test]
+    fn test_parse_price() {
+        assert_eq!(
+            parse_price("$1,299.99"),
+            Some((1299.99, Some("USD".to_string())))
+        );
+        assert_eq!(
+            parse_price("1.299,50 €"),
+            Some((1299.5, Some("EUR".to_string())))
+        );
+        assert_eq!(parse_price("CA$45"), Some((45.0, Some("CAD".to_string()))));
+        assert_eq!(
+            parse_price("MX$199"),
+            Some((199.0, Some("MXN".to_string())))
+        );
+        assert_eq!(parse_price("S$45"), Some((45.0, Some("SGD".to_string()))));
+        assert_eq!(
+            parse_price("NT$300"),
+            Some((300.0, Some("TWD".to_string())))
+        );
+        assert_eq!(parse_price("Z$10"), Some((10.0, None)));
+        assert_eq!(parse_price("CHF 12"), Some((12.0, Some("CHF".to_string()))));
+        assert_eq!(parse_price("1,000"), Some((1000.0, None)));
+        assert_eq!(parse_price("Free"), None);
+    }
+
+    #[test]
+    fn test_is_iso_date() {
+        assert!(is_iso_date("2024-12-31"));
+        assert!(!is_iso_date("2024-13-01"));
+        assert!(!is_iso_date("12/31/2024"));
+        assert!(!is_iso_date("2024-1-1"));
+    }
+
+    #[test]
+    fn test_parse_news_date() {
+        assert_eq!(
+            parse_news_date("07/17/2024, 07:00 AM, +0000 UTC").as_deref(),
+            Some("2024-07-17T07:00:00Z")
+        );
+        assert_eq!(
+            parse_news_date("12/01/2023, 12:30 PM, -0500 EST").as_deref(),
+            Some("2023-12-01T12:30:00-05:00")
+        );
+        assert_eq!(parse_news_date("2 hours ago"), None);
+    }
+}
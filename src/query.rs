@@ -1,5 +1,64 @@
 use crate::error::{SerpError, SerpResult};
+use crate::geo::{self, GeoPoint};
 use serde::Serialize;
+use tracing::warn;
+
+/// Parameters that can never be set through [`SearchQueryBuilder::param`].
+const RESERVED_PARAMS: &[&str] = &["api_key", "q"];
+
+/// Parameters already covered by a typed [`SearchQueryBuilder`] method.
+const TYPED_PARAMS: &[&str] = &[
+    "hl",
+    "gl",
+    "google_domain",
+    "num",
+    "start",
+    "device",
+    "safe",
+    "tbm",
+    "location",
+    "uule",
+    "ll",
+];
+
+/// Parameter values equal to SerpAPI's defaults, dropped from the canonical form.
+const DEFAULT_PARAMS: &[(&str, &str)] = &[
+    ("engine", "google"),
+    ("google_domain", "google.com"),
+    ("hl", "en"),
+    ("gl", "us"),
+    ("num", "10"),
+    ("start", "0"),
+    ("device", "desktop"),
+    ("safe", "off"),
+];
+
+/// Parameters whose values SerpAPI treats case-insensitively.
+const CASE_INSENSITIVE_PARAMS: &[&str] = &["google_domain", "hl", "gl", "device", "safe", "tbm"];
+
+/// Google browser-session parameters that carry no search semantics.
+const GOOGLE_SESSION_PARAMS: &[&str] = &[
+    "oq", "aqs", "sourceid", "ie", "oe", "ei", "ved", "sxsrf", "gs_lcp", "gs_lp", "gs_ssp",
+    "sclient", "uact", "biw", "bih", "dpr", "client", "rlz", "sa", "source", "iflsig", "fbs",
+    "sca_esv", "sca_upv",
+];
+
+/// SerpAPI parameters that have no equivalent on google.com.
+const SERPAPI_ONLY_PARAMS: &[&str] = &[
+    "engine",
+    "google_domain",
+    "device",
+    "location",
+    "ll",
+    "no_cache",
+    "async",
+    "zero_trace",
+    "output",
+    "json_restrictor",
+];
+
+/// Location parameters of which SerpAPI accepts at most one per request.
+const EXCLUSIVE_LOCATION_PARAMS: &[&str] = &["location", "uule", "ll"];
 
 /// Fluent interface for building search queries
 #[derive(Debug, Clone, Serialize)]
@@ -34,6 +93,15 @@ pub struct SearchQuery {
     #[serde(skip_serializing_if = "Option::is_none")]
     location: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uule: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ll: Option<String>,
+
+    #[serde(skip)]
+    extra_params: Vec<(String, String)>,
+
     #[serde(skip)]
     api_key: String,
 }
@@ -45,6 +113,104 @@ impl SearchQuery {
         SearchQueryBuilder::new(query)
     }
 
+    /// Parse a Google search URL or a SerpAPI playground/search URL
+    ///
+    /// Recognized parameters are mapped to their typed fields, the Google domain is
+    /// taken from the host of google.* URLs, browser-session parameters such as
+    /// `ei` or `ved` are dropped and everything else is kept as extra parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serp_sdk::SearchQuery;
+    ///
+    /// let builder = SearchQuery::from_url(
+    ///     "https://www.google.co.uk/search?q=rust+async&hl=en&num=20&lr=lang_en&ved=abc",
+    /// )?;
+    ///
+    /// let query = builder.as_query();
+    /// assert_eq!(query.query(), "rust async");
+    /// assert_eq!(query.extra_params(), &[("lr".to_string(), "lang_en".to_string())]);
+    /// # Ok::<(), serp_sdk::SerpError>(())
+    /// ```
+    pub fn from_url(url: &str) -> SerpResult<SearchQueryBuilder> {
+        let parsed = url::Url::parse(url)
+            .map_err(|e| SerpError::InvalidParameter(format!("invalid URL '{}': {}", url, e)))?;
+
+        let mut builder = Self::from_query_string(parsed.query().unwrap_or_default())?;
+
+        if let Some(host) = parsed.host_str() {
+            let host = host.strip_prefix("www.").unwrap_or(host);
+            if host.starts_with("google.") && builder.inner.google_domain.is_none() {
+                builder.inner.google_domain = Some(host.to_string());
+            }
+        }
+        Ok(builder)
+    }
+
+    /// Parse a URL-encoded query string such as `q=coffee&hl=en&gl=us`
+    ///
+    /// See [`from_url`](Self::from_url) for how parameters are mapped.
+    pub fn from_query_string(query_string: &str) -> SerpResult<SearchQueryBuilder> {
+        let query_string = query_string.trim_start_matches('?');
+        let pairs: Vec<(String, String)> = url::form_urlencoded::parse(query_string.as_bytes())
+            .into_owned()
+            .collect();
+
+        let q = pairs
+            .iter()
+            .find(|(key, _)| key == "q")
+            .map(|(_, value)| value.clone())
+            .ok_or_else(|| {
+                SerpError::InvalidParameter("query string has no 'q' parameter".to_string())
+            })?;
+
+        let mut builder = SearchQueryBuilder::new(q);
+        for (key, value) in pairs {
+            builder = match key.as_str() {
+                "q" | "api_key" => builder,
+                "engine" if value == "google" => builder,
+                "hl" => builder.language(value),
+                "gl" => builder.country(value),
+                "google_domain" => builder.domain(value),
+                "num" => builder.limit(parse_number(&key, &value)?)?,
+                "start" => builder.offset(parse_number(&key, &value)?),
+                "device" => builder.device(value),
+                "safe" => builder.safe_search(value),
+                "tbm" => builder.search_type(value),
+                "location" => builder.location(value),
+                "uule" => builder.uule(value),
+                "ll" => {
+                    builder.inner.ll = Some(value);
+                    builder
+                }
+                key if GOOGLE_SESSION_PARAMS.contains(&key) => builder,
+                _ => builder.param(key, value)?,
+            };
+        }
+        Ok(builder)
+    }
+
+    /// Get an equivalent google.com search URL for this query
+    ///
+    /// SerpAPI-only parameters such as `location` or `device` have no Google
+    /// equivalent and are omitted; use [`SearchQueryBuilder::uule_location`] to
+    /// keep location targeting in the URL.
+    pub fn google_url(&self) -> SerpResult<String> {
+        let params: Vec<(String, String)> = self
+            .params()?
+            .into_iter()
+            .filter(|(key, _)| !SERPAPI_ONLY_PARAMS.contains(&key.as_str()))
+            .collect();
+        let domain = self.google_domain.as_deref().unwrap_or("google.com");
+
+        Ok(format!(
+            "https://www.{}/search?{}",
+            domain,
+            serde_urlencoded::to_string(params)?
+        ))
+    }
+
     /// Get the query string
     pub fn query(&self) -> &str {
         &self.query
@@ -56,9 +222,107 @@ impl SearchQuery {
         &self.api_key
     }
 
+    /// Get the extra parameters set with [`SearchQueryBuilder::param`]
+    pub fn extra_params(&self) -> &[(String, String)] {
+        &self.extra_params
+    }
+
+    /// Get all request parameters, excluding the API key
+    ///
+    /// Extra parameters take precedence over typed fields with the same key.
+    pub fn params(&self) -> SerpResult<Vec<(String, String)>> {
+        let typed = serde_urlencoded::to_string(self)?;
+        let mut params: Vec<(String, String)> = url::form_urlencoded::parse(typed.as_bytes())
+            .into_owned()
+            .collect();
+
+        for (key, value) in &self.extra_params {
+            params.retain(|(existing, _)| existing != key);
+            params.push((key.clone(), value.clone()));
+        }
+
+        Ok(params)
+    }
+
+    /// Get the canonical form of the request parameters
+    ///
+    /// Two queries with equal canonical parameters hit the same SerpAPI result:
+    /// parameters are sorted by key, empty values and SerpAPI defaults are
+    /// dropped, whitespace in `q` is collapsed and case-insensitive values are
+    /// lowercased. The API key is never included.
+    pub fn canonical_params(&self) -> SerpResult<Vec<(String, String)>> {
+        let mut params: Vec<(String, String)> = self
+            .params()?
+            .into_iter()
+            .map(|(key, value)| {
+                let value = if key == "q" {
+                    value.split_whitespace().collect::<Vec<_>>().join(" ")
+                } else if CASE_INSENSITIVE_PARAMS.contains(&key.as_str()) {
+                    value.trim().to_ascii_lowercase()
+                } else {
+                    value.trim().to_string()
+                };
+                (key, value)
+            })
+            .filter(|(key, value)| {
+                !value.is_empty() && !DEFAULT_PARAMS.contains(&(key.as_str(), value.as_str()))
+            })
+            .collect();
+
+        params.sort();
+        Ok(params)
+    }
+
+    /// Get the canonical form as a URL-encoded query string
+    pub fn canonical_query_string(&self) -> SerpResult<String> {
+        Ok(serde_urlencoded::to_string(self.canonical_params()?)?)
+    }
+
+    /// Get a stable fingerprint of this query
+    ///
+    /// The fingerprint is the 64-bit FNV-1a hash of the
+    /// [canonical query string](Self::canonical_query_string), formatted as 16 hex
+    /// digits. It does not depend on the process, platform or API key, which makes
+    /// it suitable as a cache key, deduplication key or log correlation ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serp_sdk::SearchQuery;
+    ///
+    /// let a = SearchQuery::new("rust  async").language("en").country("de");
+    /// let b = SearchQuery::new("rust async").country("DE");
+    /// assert_eq!(a.as_query().fingerprint()?, b.as_query().fingerprint()?);
+    /// # Ok::<(), serp_sdk::SerpError>(())
+    /// ```
+    pub fn fingerprint(&self) -> SerpResult<String> {
+        Ok(format!(
+            "{:016x}",
+            fnv1a_64(self.canonical_query_string()?.as_bytes())
+        ))
+    }
+
+    /// Check parameter combinations that SerpAPI would reject
+    fn validate(&self) -> SerpResult<()> {
+        let params = self.params()?;
+        let locations: Vec<&str> = params
+            .iter()
+            .map(|(key, _)| key.as_str())
+            .filter(|key| EXCLUSIVE_LOCATION_PARAMS.contains(key))
+            .collect();
+
+        if locations.len() > 1 {
+            return Err(SerpError::InvalidParameter(format!(
+                "only one of location, uule and ll can be set, got: {}",
+                locations.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
     /// Convert to URL-encoded query string
     pub fn to_query_string(&self) -> SerpResult<String> {
-        let mut params = serde_urlencoded::to_string(self)?;
+        let mut params = serde_urlencoded::to_string(self.params()?)?;
         params.push_str(&format!("&api_key={}", self.api_key));
         Ok(params)
     }
@@ -85,11 +349,19 @@ impl SearchQueryBuilder {
                 safe: None,
                 tbm: None,
                 location: None,
+                uule: None,
+                ll: None,
+                extra_params: Vec::new(),
                 api_key: String::new(),
             },
         }
     }
 
+    /// Get the query being built
+    pub fn as_query(&self) -> &SearchQuery {
+        &self.inner
+    }
+
     /// Set the interface language (hl parameter)
     /// Common values: "en", "es", "fr", "de", "ja", "ko", "zh", etc.
     pub fn language(mut self, hl: impl Into<String>) -> Self {
@@ -156,16 +428,76 @@ impl SearchQueryBuilder {
         self
     }
 
+    /// Set a pre-encoded Google location (uule parameter)
+    ///
+    /// Cannot be combined with [`location`](Self::location) or [`ll`](Self::ll).
+    pub fn uule(mut self, uule: impl Into<String>) -> Self {
+        self.inner.uule = Some(uule.into());
+        self
+    }
+
+    /// Target a canonical location name by encoding it as a uule parameter
+    /// Examples: "Austin,Texas,United States", "London,England,United Kingdom"
+    pub fn uule_location(self, canonical_name: &str) -> SerpResult<Self> {
+        Ok(self.uule(geo::encode_uule(canonical_name)?))
+    }
+
+    /// Target precise coordinates by encoding them as a uule parameter
+    pub fn coordinates(self, point: GeoPoint) -> Self {
+        self.uule(point.uule())
+    }
+
+    /// Set GPS coordinates and zoom level (ll parameter, used by Google Maps)
+    /// Zoom ranges from 3 (world) to 21 (street)
+    pub fn ll(mut self, point: GeoPoint, zoom: u8) -> SerpResult<Self> {
+        self.inner.ll = Some(point.ll(zoom)?);
+        Ok(self)
+    }
+
+    /// Set an arbitrary SerpAPI parameter not covered by the typed methods
+    /// Examples: "lr", "cr", "filter", "nfpr", "uule", "kgmid", "tbs"
+    ///
+    /// Setting the same key twice replaces the previous value. A key that
+    /// matches a typed field (e.g. "hl") overrides that field and logs a warning.
+    /// The reserved keys "api_key" and "q" are rejected.
+    pub fn param(mut self, key: impl Into<String>, value: impl Into<String>) -> SerpResult<Self> {
+        let key = key.into();
+        if key.is_empty() || RESERVED_PARAMS.contains(&key.as_str()) {
+            return Err(SerpError::InvalidParameter(format!(
+                "parameter '{}' cannot be set with param()",
+                key
+            )));
+        }
+        if TYPED_PARAMS.contains(&key.as_str()) {
+            warn!(
+                "Parameter '{}' overrides the typed field of the same name",
+                key
+            );
+        }
+
+        let value = value.into();
+        match self.inner.extra_params.iter_mut().find(|(k, _)| *k == key) {
+            Some(entry) => entry.1 = value,
+            None => self.inner.extra_params.push((key, value)),
+        }
+        Ok(self)
+    }
+
     /// Build the search query (internal use)
-    pub(crate) fn build(mut self, api_key: String) -> SearchQuery {
+    pub(crate) fn build(mut self, api_key: String) -> SerpResult<SearchQuery> {
+        self.inner.validate()?;
         self.inner.api_key = api_key;
-        self.inner
+        Ok(self.inner)
     }
 }
 
 /// Specialized query builders for different search types
 impl SearchQueryBuilder {
     /// Configure for image search
+    ///
+    /// Images are returned in [`SearchResults::images_results`](crate::SearchResults::images_results);
+    /// for image filters use
+    /// [`GoogleImagesQuery`](crate::engines::google_images::GoogleImagesQuery).
     pub fn images(self) -> Self {
         self.search_type("isch")
     }
@@ -176,16 +508,38 @@ impl SearchQueryBuilder {
     }
 
     /// Configure for news search
+    ///
+    /// For topic browsing and full story coverage use
+    /// [`GoogleNewsQuery`](crate::engines::google_news::GoogleNewsQuery).
     pub fn news(self) -> Self {
         self.search_type("nws")
     }
 
     /// Configure for shopping search
+    ///
+    /// For price filters and product details use
+    /// [`GoogleShoppingQuery`](crate::engines::google_shopping::GoogleShoppingQuery).
     pub fn shopping(self) -> Self {
         self.search_type("shop")
     }
 }
 
+fn parse_number(key: &str, value: &str) -> SerpResult<u32> {
+    value.parse().map_err(|_| {
+        SerpError::InvalidParameter(format!("'{}' must be a number, got '{}'", key, value))
+    })
+}
+
+/// 64-bit FNV-1a, chosen because its output is fixed by specification.
+fn fnv1a_64(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -197,7 +551,8 @@ mod tests {
             .country("us")
             .limit(10)
             .unwrap()
-            .build("test-key".to_string());
+            .build("test-key".to_string())
+            .unwrap();
 
         assert_eq!(query.query(), "rust programming");
         assert_eq!(query.language.as_ref().unwrap(), "en");
@@ -218,8 +573,182 @@ mod tests {
     fn test_specialized_builders() {
         let query = SearchQuery::new("cats")
             .images()
-            .build("test-key".to_string());
+            .build("test-key".to_string())
+            .unwrap();
 
         assert_eq!(query.tbm.as_ref().unwrap(), "isch");
     }
+
+    #[test]
+    fn test_extra_params() {
+        let query = SearchQuery::new("rust")
+            .country("us")
+            .param("lr", "lang_en")
+            .unwrap()
+            .param("filter", "1")
+            .unwrap()
+            .param("filter", "0")
+            .unwrap()
+            .param("gl", "de")
+            .unwrap()
+            .build("test-key".to_string())
+            .unwrap();
+
+        assert_eq!(
+            query.to_query_string().unwrap(),
+            "q=rust&lr=lang_en&filter=0&gl=de&api_key=test-key"
+        );
+    }
+
+    #[test]
+    fn test_geo_targeting() {
+        let point = GeoPoint::new(30.2672, -97.7431).unwrap();
+        let query = SearchQuery::new("coffee")
+            .coordinates(point)
+            .build("test-key".to_string())
+            .unwrap();
+        assert_eq!(query.uule, Some(point.uule()));
+
+        let query = SearchQuery::new("coffee")
+            .ll(point, 14)
+            .unwrap()
+            .build("test-key".to_string())
+            .unwrap();
+        assert_eq!(query.ll.as_deref(), Some("@30.2672,-97.7431,14z"));
+    }
+
+    #[test]
+    fn test_exclusive_location_params() {
+        let result = SearchQuery::new("coffee")
+            .location("Austin, Texas")
+            .uule_location("Austin,Texas,United States")
+            .unwrap()
+            .build("test-key".to_string());
+        assert!(matches!(result, Err(SerpError::InvalidParameter(_))));
+
+        let result = SearchQuery::new("coffee")
+            .location("Austin, Texas")
+            .param("uule", "w+CAIQICI")
+            .unwrap()
+            .build("test-key".to_string());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_canonical_params() {
+        let query = SearchQuery::new("  rust   web ")
+            .language("en")
+            .country("DE")
+            .device("desktop")
+            .offset(0)
+            .param("lr", "")
+            .unwrap()
+            .param("filter", "0")
+            .unwrap()
+            .build("secret".to_string())
+            .unwrap();
+
+        assert_eq!(
+            query.canonical_query_string().unwrap(),
+            "filter=0&gl=de&q=rust+web"
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_is_stable() {
+        let a = SearchQuery::new("rust")
+            .country("de")
+            .limit(20)
+            .unwrap()
+            .build("key-a".to_string())
+            .unwrap();
+        let b = SearchQuery::new("rust")
+            .limit(20)
+            .unwrap()
+            .country("de")
+            .language("en")
+            .build("key-b".to_string())
+            .unwrap();
+        let c = SearchQuery::new("rust")
+            .country("fr")
+            .build("key-a".to_string())
+            .unwrap();
+
+        assert_eq!(a.fingerprint().unwrap(), b.fingerprint().unwrap());
+        assert_ne!(a.fingerprint().unwrap(), c.fingerprint().unwrap());
+        assert_eq!(fnv1a_64(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a_64(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn test_from_url() {
+        let builder = SearchQuery::from_url(
+            "https://www.google.de/search?q=rust+%22async%22&hl=de&num=20&start=10&tbm=nws&lr=lang_de&ei=xyz&ved=abc",
+        )
+        .unwrap();
+        let query = builder.build("key".to_string()).unwrap();
+
+        assert_eq!(query.query(), "rust \"async\"");
+        assert_eq!(query.google_domain.as_deref(), Some("google.de"));
+        assert_eq!(query.language.as_deref(), Some("de"));
+        assert_eq!(query.num, Some(20));
+        assert_eq!(query.start, Some(10));
+        assert_eq!(query.tbm.as_deref(), Some("nws"));
+        assert_eq!(
+            query.extra_params(),
+            &[("lr".to_string(), "lang_de".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_from_serpapi_url() {
+        let builder = SearchQuery::from_url(
+            "https://serpapi.com/playground?engine=google&q=Coffee&location=Austin%2C+Texas%2C+United+States&gl=us&api_key=secret",
+        )
+        .unwrap();
+        let query = builder.build("key".to_string()).unwrap();
+
+        assert_eq!(query.query(), "Coffee");
+        assert_eq!(
+            query.location.as_deref(),
+            Some("Austin, Texas, United States")
+        );
+        assert!(query.google_domain.is_none());
+        assert!(query.extra_params().is_empty());
+
+        assert!(SearchQuery::from_query_string("hl=en").is_err());
+        assert!(SearchQuery::from_query_string("q=rust&num=abc").is_err());
+    }
+
+    #[test]
+    fn test_google_url_round_trip() {
+        let query = SearchQuery::new("rust web")
+            .domain("google.fr")
+            .language("fr")
+            .device("mobile")
+            .param("lr", "lang_fr")
+            .unwrap()
+            .build("secret".to_string())
+            .unwrap();
+
+        let url = query.google_url().unwrap();
+        assert_eq!(
+            url,
+            "https://www.google.fr/search?q=rust+web&hl=fr&lr=lang_fr"
+        );
+
+        let parsed = SearchQuery::from_url(&url)
+            .unwrap()
+            .device("mobile")
+            .build("other".to_string())
+            .unwrap();
+        assert_eq!(parsed.fingerprint().unwrap(), query.fingerprint().unwrap());
+    }
+
+    #[test]
+    fn test_reserved_params_rejected() {
+        assert!(SearchQuery::new("rust").param("api_key", "other").is_err());
+        assert!(SearchQuery::new("rust").param("q", "other").is_err());
+        assert!(SearchQuery::new("rust").param("", "value").is_err());
+    }
 }
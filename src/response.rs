@@ -62,10 +62,34 @@ pub struct SearchResults {
     pub video_results: Option<Vec<VideoResult>>,
     /// Inline image results
     pub inline_images: Option<Vec<InlineImage>>,
+    /// Image search results (tbm=isch)
+    pub images_results: Option<Vec<ImageResult>>,
+    /// Suggested refinements of an image search
+    pub suggested_searches: Option<Vec<SuggestedSearch>>,
     /// Inline video results
     pub inline_videos: Option<Vec<InlineVideo>>,
     /// Short video results
     pub short_videos: Option<Vec<ShortVideo>>,
+    /// Google's AI-generated overview of the query
+    pub ai_overview: Option<AiOverview>,
+    /// "People also ask" questions
+    pub related_questions: Option<Vec<RelatedQuestion>>,
+    /// Top stories carousel
+    pub top_stories: Option<Vec<TopStory>>,
+    /// Recent posts from an X (Twitter) account
+    pub twitter_results: Option<TwitterResults>,
+    /// Recipe cards
+    pub recipes_results: Option<Vec<RecipeResult>>,
+    /// Products shown in the popular products block
+    pub immersive_products: Option<Vec<ImmersiveProduct>>,
+    /// Threads from discussion sites and forums
+    pub discussions_and_forums: Option<Vec<DiscussionResult>>,
+    /// Posts and articles from the perspectives block
+    pub perspectives: Option<Vec<Perspective>>,
+    /// Carousel of entities at the top of the page (e.g. a list of films)
+    pub top_carousel: Option<Vec<CarouselItem>>,
+    /// Scores, fixtures and standings for a team or league
+    pub sports_results: Option<SportsResults>,
     /// Search information
     pub search_information: Option<SearchInformation>,
     /// SerpAPI pagination
@@ -150,13 +174,261 @@ pub struct Source {
     pub security: Option<String>,
 }
 
-/// Answer box result
+/// Answer box shown above the organic results, typed by its `type` field.
+///
+/// Answer types without a dedicated variant, or whose shape has changed, are
+/// kept in [`AnswerBox::Other`] with the fields shared by most answers.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serp_sdk::{SerpClient, SearchQuery};
+/// use serp_sdk::response::AnswerBox;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = SerpClient::builder().api_key("test").build()?;
+/// let results = client.search(SearchQuery::new("100 usd to eur")).await?;
+///
+/// match results.answer_box {
+///     Some(AnswerBox::CurrencyConverter(converter)) => println!("{:?}", converter.result),
+///     Some(AnswerBox::Weather(weather)) => println!("{:?}°", weather.temperature),
+///     Some(answer) => println!("{}: {:?}", answer.answer_type(), answer.text()),
+///     None => {}
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "type")]
+pub enum AnswerBox {
+    #[serde(rename = "calculator_result")]
+    Calculator(CalculatorAnswer),
+    #[serde(rename = "dictionary_results")]
+    Dictionary(DictionaryAnswer),
+    #[serde(rename = "weather_result")]
+    Weather(WeatherAnswer),
+    #[serde(rename = "finance_results")]
+    Finance(FinanceAnswer),
+    #[serde(rename = "translation_result")]
+    Translation(TranslationAnswer),
+    #[serde(rename = "currency_converter")]
+    CurrencyConverter(CurrencyConverterAnswer),
+    #[serde(rename = "population_result")]
+    Population(PopulationAnswer),
+    #[serde(rename = "organic_result")]
+    Organic(OrganicAnswer),
+    #[serde(untagged)]
+    Other(GenericAnswer),
+}
+
+impl AnswerBox {
+    /// The `type` SerpAPI reported for this answer
+    pub fn answer_type(&self) -> &str {
+        match self {
+            Self::Calculator(_) => "calculator_result",
+            Self::Dictionary(_) => "dictionary_results",
+            Self::Weather(_) => "weather_result",
+            Self::Finance(_) => "finance_results",
+            Self::Translation(_) => "translation_result",
+            Self::CurrencyConverter(_) => "currency_converter",
+            Self::Population(_) => "population_result",
+            Self::Organic(_) => "organic_result",
+            Self::Other(answer) => answer.answer_type.as_deref().unwrap_or_default(),
+        }
+    }
+
+    /// Short textual answer, when the answer type has one
+    pub fn text(&self) -> Option<String> {
+        match self {
+            Self::Calculator(answer) => answer.result.clone(),
+            Self::Dictionary(answer) => answer.definitions.as_ref()?.first().cloned(),
+            Self::Weather(answer) => answer.weather.clone(),
+            Self::Finance(answer) => answer.price.map(|price| match &answer.currency {
+                Some(currency) => format!("{} {}", price, currency),
+                None => price.to_string(),
+            }),
+            Self::Translation(answer) => answer.translation.as_ref()?.target.as_ref()?.text.clone(),
+            Self::CurrencyConverter(answer) => answer.result.clone(),
+            Self::Population(answer) => answer.population.clone(),
+            Self::Organic(answer) => answer.answer.clone().or(answer.snippet.clone()),
+            Self::Other(answer) => answer
+                .answer
+                .clone()
+                .or(answer.result.clone())
+                .or(answer.snippet.clone()),
+        }
+    }
+
+    /// Title of the answer, when it has one
+    pub fn title(&self) -> Option<&str> {
+        match self {
+            Self::Finance(answer) => answer.title.as_deref(),
+            Self::Organic(answer) => answer.title.as_deref(),
+            Self::Other(answer) => answer.title.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Snippet quoted from the source page, when the answer has one
+    pub fn snippet(&self) -> Option<&str> {
+        match self {
+            Self::Organic(answer) => answer.snippet.as_deref(),
+            Self::Other(answer) => answer.snippet.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Link to the page the answer was taken from, when it has one
+    pub fn link(&self) -> Option<&str> {
+        match self {
+            Self::Organic(answer) => answer.link.as_deref(),
+            Self::Other(answer) => answer.link.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Calculator answer (e.g. "12 * 7")
+#[derive(Debug, Deserialize, Clone)]
+pub struct CalculatorAnswer {
+    /// e.g. "12 * 7 = 84"
+    pub result: Option<String>,
+}
+
+/// Dictionary definition of a word
+#[derive(Debug, Deserialize, Clone)]
+pub struct DictionaryAnswer {
+    pub syllables: Option<String>,
+    pub phonetic: Option<String>,
+    /// e.g. "noun"
+    pub word_type: Option<String>,
+    pub definitions: Option<Vec<String>>,
+    pub examples: Option<Vec<String>>,
+}
+
+/// Current weather and forecast for a location
+#[derive(Debug, Deserialize, Clone)]
+pub struct WeatherAnswer {
+    pub location: Option<String>,
+    pub date: Option<String>,
+    /// e.g. "Partly cloudy"
+    pub weather: Option<String>,
+    pub temperature: Option<String>,
+    /// e.g. "Fahrenheit"
+    pub unit: Option<String>,
+    pub precipitation: Option<String>,
+    pub humidity: Option<String>,
+    pub wind: Option<String>,
+    pub thumbnail: Option<String>,
+    pub forecast: Option<Vec<WeatherForecast>>,
+}
+
+/// Forecast for one day
+#[derive(Debug, Deserialize, Clone)]
+pub struct WeatherForecast {
+    pub day: Option<String>,
+    pub weather: Option<String>,
+    pub temperature: Option<WeatherRange>,
+    pub thumbnail: Option<String>,
+    pub precipitation: Option<String>,
+    pub humidity: Option<String>,
+    pub wind: Option<String>,
+}
+
+/// High and low temperature of a day
+#[derive(Debug, Deserialize, Clone)]
+pub struct WeatherRange {
+    pub high: Option<String>,
+    pub low: Option<String>,
+}
+
+/// Stock quote
+#[derive(Debug, Deserialize, Clone)]
+pub struct FinanceAnswer {
+    pub title: Option<String>,
+    pub exchange: Option<String>,
+    pub stock: Option<String>,
+    pub currency: Option<String>,
+    pub price: Option<f64>,
+    pub previous_close: Option<f64>,
+    pub price_movement: Option<PriceMovement>,
+}
+
+/// Google Translate answer
+#[derive(Debug, Deserialize, Clone)]
+pub struct TranslationAnswer {
+    pub translation: Option<Translation>,
+}
+
+/// Text and its translation
+#[derive(Debug, Deserialize, Clone)]
+pub struct Translation {
+    pub source: Option<TranslatedText>,
+    pub target: Option<TranslatedText>,
+}
+
+/// One side of a translation
+#[derive(Debug, Deserialize, Clone)]
+pub struct TranslatedText {
+    pub language: Option<String>,
+    pub text: Option<String>,
+    pub pronunciation: Option<String>,
+}
+
+/// Currency conversion
 #[derive(Debug, Deserialize, Clone)]
-pub struct AnswerBox {
+pub struct CurrencyConverterAnswer {
+    /// e.g. "92.31 Euro"
+    pub result: Option<String>,
+    pub currency_converter: Option<CurrencyConversion>,
+}
+
+/// Amounts on both sides of a currency conversion
+#[derive(Debug, Deserialize, Clone)]
+pub struct CurrencyConversion {
+    pub from: Option<CurrencyAmount>,
+    pub to: Option<CurrencyAmount>,
+}
+
+/// Amount in a currency
+#[derive(Debug, Deserialize, Clone)]
+pub struct CurrencyAmount {
+    pub price: Option<f64>,
+    pub currency: Option<String>,
+}
+
+/// Population of a place
+#[derive(Debug, Deserialize, Clone)]
+pub struct PopulationAnswer {
+    pub place: Option<String>,
+    /// e.g. "8.336 million"
+    pub population: Option<String>,
+    pub year: Option<String>,
+}
+
+/// Featured snippet taken from a web page
+#[derive(Debug, Deserialize, Clone)]
+pub struct OrganicAnswer {
+    pub title: Option<String>,
+    pub answer: Option<String>,
+    pub snippet: Option<String>,
+    pub snippet_highlighted_words: Option<Vec<String>>,
+    pub list: Option<Vec<String>>,
+    pub date: Option<String>,
+    pub link: Option<String>,
+    pub displayed_link: Option<String>,
+    pub thumbnail: Option<String>,
+}
+
+/// Answer of a type without a dedicated variant
+#[derive(Debug, Deserialize, Clone)]
+pub struct GenericAnswer {
     #[serde(rename = "type")]
-    pub answer_type: String,
+    pub answer_type: Option<String>,
     pub title: Option<String>,
     pub answer: Option<String>,
+    pub result: Option<String>,
     pub snippet: Option<String>,
     pub snippet_highlighted_words: Option<Vec<String>>,
     pub link: Option<String>,
@@ -240,6 +512,8 @@ pub struct ShoppingResult {
     pub product_link: Option<String>,
     pub product_id: Option<String>,
     pub serpapi_product_api: Option<String>,
+    /// Merchant (`seller` in Bing Shopping results)
+    #[serde(alias = "seller")]
     pub source: Option<String>,
     pub price: Option<String>,
     pub extracted_price: Option<f64>,
@@ -247,6 +521,128 @@ pub struct ShoppingResult {
     pub reviews: Option<u32>,
     pub extensions: Option<Vec<String>>,
     pub thumbnail: Option<String>,
+    pub old_price: Option<String>,
+    pub extracted_old_price: Option<f64>,
+    pub delivery: Option<String>,
+    pub second_hand_condition: Option<String>,
+    pub tag: Option<String>,
+}
+
+impl ShoppingResult {
+    /// Price as amount and currency
+    pub fn parsed_price(&self) -> Option<Price> {
+        Price::from_parts(self.price.as_deref(), self.extracted_price)
+    }
+
+    /// Price before a discount, as amount and currency
+    pub fn parsed_old_price(&self) -> Option<Price> {
+        Price::from_parts(self.old_price.as_deref(), self.extracted_old_price)
+    }
+}
+
+/// Price split into an amount and an ISO 4217 currency code
+#[derive(Debug, Clone, PartialEq)]
+pub struct Price {
+    pub amount: f64,
+    /// e.g. "USD"; `None` when the displayed price has no currency marker
+    pub currency: Option<String>,
+}
+
+impl Price {
+    /// Parse a displayed price such as "$1,299.99" or "1.299,00 €"
+    pub fn parse(text: &str) -> Option<Self> {
+        crate::parse::parse_price(text).map(|(amount, currency)| Self { amount, currency })
+    }
+
+    /// Combine a displayed price with the amount SerpAPI already extracted
+    pub(crate) fn from_parts(text: Option<&str>, extracted: Option<f64>) -> Option<Self> {
+        let parsed = text.and_then(Self::parse);
+        match (parsed, extracted) {
+            (Some(price), Some(amount)) => Some(Self { amount, ..price }),
+            (None, Some(amount)) => Some(Self {
+                amount,
+                currency: None,
+            }),
+            (parsed, None) => parsed,
+        }
+    }
+}
+
+/// Price, rating and seller of a product listing, in the same shape for every
+/// marketplace engine
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ListingSummary {
+    pub price: Option<Price>,
+    /// Price before a discount
+    pub old_price: Option<Price>,
+    pub rating: Option<f32>,
+    pub reviews: Option<u64>,
+    pub seller: Option<String>,
+}
+
+/// Price change over the quoted period
+#[derive(Debug, Deserialize, Clone)]
+pub struct PriceMovement {
+    pub percentage: Option<f64>,
+    /// Absolute change (`price` in finance answer boxes)
+    #[serde(alias = "price")]
+    pub value: Option<f64>,
+    /// "Up" or "Down"
+    pub movement: Option<String>,
+    /// Period of the change, e.g. "Today" or "After hours"
+    pub date: Option<String>,
+}
+
+impl PriceMovement {
+    fn sign(&self) -> f64 {
+        if self.movement.as_deref() == Some("Down") {
+            -1.0
+        } else {
+            1.0
+        }
+    }
+
+    /// Absolute change, negative when the price went down
+    pub fn signed_value(&self) -> Option<f64> {
+        self.value.map(|v| v.abs() * self.sign())
+    }
+
+    /// Percentage change, negative when the price went down
+    pub fn signed_percentage(&self) -> Option<f64> {
+        self.percentage.map(|p| p.abs() * self.sign())
+    }
+}
+
+/// Product listing that can be compared across shopping and marketplace engines
+pub trait ProductListing {
+    /// Listing title
+    fn title(&self) -> &str;
+
+    /// Link to the listing on the marketplace
+    fn link(&self) -> Option<&str>;
+
+    /// Normalized price, rating and seller
+    fn summary(&self) -> ListingSummary;
+}
+
+impl ProductListing for ShoppingResult {
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn link(&self) -> Option<&str> {
+        self.link.as_deref().or(self.product_link.as_deref())
+    }
+
+    fn summary(&self) -> ListingSummary {
+        ListingSummary {
+            price: self.parsed_price(),
+            old_price: self.parsed_old_price(),
+            rating: self.rating.map(|r| r as f32),
+            reviews: self.reviews.map(u64::from),
+            seller: self.source.clone(),
+        }
+    }
 }
 
 /// Local results
@@ -261,20 +657,23 @@ pub struct LocalResults {
 pub struct LocalPlace {
     pub position: Option<u32>,
     pub title: String,
-    pub place_id: String,
-    pub data_id: String,
-    pub data_cid: String,
-    pub reviews_link: String,
-    pub photos_link: String,
+    pub place_id: Option<String>,
+    pub data_id: Option<String>,
+    pub data_cid: Option<String>,
+    pub reviews_link: Option<String>,
+    pub photos_link: Option<String>,
     pub gps_coordinates: Option<GpsCoordinates>,
-    pub place_id_search: String,
-    pub provider_id: String,
+    pub place_id_search: Option<String>,
+    pub provider_id: Option<String>,
     pub rating: Option<f64>,
     pub reviews: Option<u32>,
     pub price: Option<String>,
+    #[serde(rename = "type")]
     pub type_: Option<String>,
     pub types: Option<Vec<String>>,
-    pub address: String,
+    pub type_id: Option<String>,
+    pub type_ids: Option<Vec<String>>,
+    pub address: Option<String>,
     pub open_state: Option<String>,
     pub hours: Option<String>,
     pub operating_hours: Option<HashMap<String, String>>,
@@ -282,6 +681,7 @@ pub struct LocalPlace {
     pub website: Option<String>,
     pub description: Option<String>,
     pub service_options: Option<HashMap<String, bool>>,
+    pub thumbnail: Option<String>,
 }
 
 /// GPS coordinates
@@ -327,10 +727,40 @@ pub struct InlineImage {
     pub source_name: Option<String>,
     pub source_logo: Option<String>,
     pub thumbnail: Option<String>,
+    /// Full-size image (`image` in Google Lens results)
+    #[serde(alias = "image")]
     pub original: Option<String>,
     pub is_product: Option<bool>,
 }
 
+/// Image search result
+#[derive(Debug, Deserialize, Clone)]
+pub struct ImageResult {
+    pub position: Option<u32>,
+    pub title: Option<String>,
+    pub link: Option<String>,
+    pub source: Option<String>,
+    pub source_logo: Option<String>,
+    pub thumbnail: Option<String>,
+    pub original: Option<String>,
+    pub original_width: Option<u32>,
+    pub original_height: Option<u32>,
+    pub is_product: Option<bool>,
+    pub tag: Option<String>,
+    pub related_content_id: Option<String>,
+    pub serpapi_related_content_link: Option<String>,
+}
+
+/// Suggested search chip
+#[derive(Debug, Deserialize, Clone)]
+pub struct SuggestedSearch {
+    pub name: String,
+    pub link: Option<String>,
+    pub chips: Option<String>,
+    pub serpapi_link: Option<String>,
+    pub thumbnail: Option<String>,
+}
+
 /// Inline video result
 #[derive(Debug, Deserialize, Clone)]
 pub struct InlineVideo {
@@ -365,6 +795,251 @@ pub struct ShortVideo {
     pub platform: Option<String>,
 }
 
+/// AI-generated overview of the query
+///
+/// When Google loads the overview separately only `page_token` and
+/// `serpapi_link` are set, and the overview is fetched with the
+/// `google_ai_overview` engine.
+#[derive(Debug, Deserialize, Clone)]
+pub struct AiOverview {
+    pub text_blocks: Option<Vec<AiOverviewBlock>>,
+    pub references: Option<Vec<AiOverviewReference>>,
+    pub page_token: Option<String>,
+    pub serpapi_link: Option<String>,
+}
+
+impl AiOverview {
+    /// Overview as plain text, one block per line
+    pub fn text(&self) -> String {
+        fn collect(blocks: &[AiOverviewBlock], lines: &mut Vec<String>) {
+            for block in blocks {
+                let line: Vec<&str> = [&block.title, &block.snippet]
+                    .into_iter()
+                    .flatten()
+                    .map(String::as_str)
+                    .collect();
+                if !line.is_empty() {
+                    lines.push(line.join(" "));
+                }
+                if let Some(list) = &block.list {
+                    collect(list, lines);
+                }
+                if let Some(nested) = &block.text_blocks {
+                    collect(nested, lines);
+                }
+            }
+        }
+
+        let mut lines = Vec::new();
+        collect(self.text_blocks.as_deref().unwrap_or_default(), &mut lines);
+        lines.join("\n")
+    }
+}
+
+/// Paragraph, heading, list or list item of an AI overview
+#[derive(Debug, Deserialize, Clone)]
+pub struct AiOverviewBlock {
+    /// "paragraph", "heading", "list" or "expandable"; list items have no type
+    #[serde(rename = "type")]
+    pub block_type: Option<String>,
+    /// Bold lead-in of a list item
+    pub title: Option<String>,
+    pub snippet: Option<String>,
+    pub snippet_highlighted_words: Option<Vec<String>>,
+    /// Items of a list block
+    pub list: Option<Vec<AiOverviewBlock>>,
+    /// Content of an expandable block
+    pub text_blocks: Option<Vec<AiOverviewBlock>>,
+    /// Indexes into [`AiOverview::references`]
+    pub reference_indexes: Option<Vec<u32>>,
+}
+
+/// Page cited by an AI overview
+#[derive(Debug, Deserialize, Clone)]
+pub struct AiOverviewReference {
+    pub index: Option<u32>,
+    pub title: Option<String>,
+    pub link: Option<String>,
+    pub snippet: Option<String>,
+    pub source: Option<String>,
+}
+
+/// "People also ask" question
+#[derive(Debug, Deserialize, Clone)]
+pub struct RelatedQuestion {
+    pub question: String,
+    pub snippet: Option<String>,
+    pub title: Option<String>,
+    pub date: Option<String>,
+    pub link: Option<String>,
+    pub displayed_link: Option<String>,
+    pub list: Option<Vec<String>>,
+    pub thumbnail: Option<String>,
+    /// Token for expanding the question with the `google_related_questions` engine
+    pub next_page_token: Option<String>,
+    pub serpapi_link: Option<String>,
+}
+
+/// Top stories article
+#[derive(Debug, Deserialize, Clone)]
+pub struct TopStory {
+    pub title: String,
+    pub link: Option<String>,
+    pub source: Option<String>,
+    pub date: Option<String>,
+    pub live: Option<bool>,
+    pub thumbnail: Option<String>,
+}
+
+/// X (Twitter) account block
+#[derive(Debug, Deserialize, Clone)]
+pub struct TwitterResults {
+    pub title: Option<String>,
+    pub link: Option<String>,
+    pub displayed_link: Option<String>,
+    pub tweets: Option<Vec<Tweet>>,
+}
+
+/// Post shown in a [`TwitterResults`] block
+#[derive(Debug, Deserialize, Clone)]
+pub struct Tweet {
+    pub link: Option<String>,
+    pub snippet: Option<String>,
+    pub published_date: Option<String>,
+}
+
+/// Recipe card
+#[derive(Debug, Deserialize, Clone)]
+pub struct RecipeResult {
+    pub title: String,
+    pub link: Option<String>,
+    pub source: Option<String>,
+    pub rating: Option<f32>,
+    pub reviews: Option<u32>,
+    /// e.g. "1 hr 10 min"
+    pub total_time: Option<String>,
+    pub ingredients: Option<Vec<String>>,
+    pub thumbnail: Option<String>,
+}
+
+/// Product from the popular products block
+#[derive(Debug, Deserialize, Clone)]
+pub struct ImmersiveProduct {
+    pub title: String,
+    pub source: Option<String>,
+    pub link: Option<String>,
+    pub price: Option<String>,
+    pub extracted_price: Option<f64>,
+    pub original_price: Option<String>,
+    pub extracted_original_price: Option<f64>,
+    pub rating: Option<f32>,
+    pub reviews: Option<u64>,
+    pub extensions: Option<Vec<String>>,
+    pub thumbnail: Option<String>,
+    pub immersive_product_page_token: Option<String>,
+    pub serpapi_link: Option<String>,
+}
+
+impl ProductListing for ImmersiveProduct {
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn link(&self) -> Option<&str> {
+        self.link.as_deref()
+    }
+
+    fn summary(&self) -> ListingSummary {
+        ListingSummary {
+            price: Price::from_parts(self.price.as_deref(), self.extracted_price),
+            old_price: Price::from_parts(
+                self.original_price.as_deref(),
+                self.extracted_original_price,
+            ),
+            rating: self.rating,
+            reviews: self.reviews,
+            seller: self.source.clone(),
+        }
+    }
+}
+
+/// Thread from a discussion site or forum
+#[derive(Debug, Deserialize, Clone)]
+pub struct DiscussionResult {
+    pub title: String,
+    pub link: Option<String>,
+    pub source: Option<String>,
+    pub date: Option<String>,
+    /// e.g. "12 answers"
+    pub extensions: Option<Vec<String>>,
+    pub answers: Option<Vec<DiscussionAnswer>>,
+}
+
+/// Answer shown under a [`DiscussionResult`]
+#[derive(Debug, Deserialize, Clone)]
+pub struct DiscussionAnswer {
+    pub snippet: Option<String>,
+    pub link: Option<String>,
+    pub extensions: Option<Vec<String>>,
+}
+
+/// Post or article from the perspectives block
+#[derive(Debug, Deserialize, Clone)]
+pub struct Perspective {
+    pub title: Option<String>,
+    pub link: Option<String>,
+    pub author: Option<String>,
+    pub source: Option<String>,
+    pub date: Option<String>,
+    pub snippet: Option<String>,
+    pub extensions: Option<Vec<String>>,
+    pub thumbnail: Option<String>,
+}
+
+/// Entity in the top carousel
+#[derive(Debug, Deserialize, Clone)]
+pub struct CarouselItem {
+    pub name: Option<String>,
+    /// e.g. release year or role
+    pub extensions: Option<Vec<String>>,
+    pub link: Option<String>,
+    pub serpapi_link: Option<String>,
+    pub image: Option<String>,
+}
+
+/// Scores, fixtures and standings for a team or league
+#[derive(Debug, Deserialize, Clone)]
+pub struct SportsResults {
+    pub title: Option<String>,
+    /// e.g. "2nd in Premier League"
+    pub rankings: Option<String>,
+    pub thumbnail: Option<String>,
+    /// Live or most relevant game
+    pub game_spotlight: Option<SportsGame>,
+    pub games: Option<Vec<SportsGame>>,
+}
+
+/// Game in a [`SportsResults`] block
+#[derive(Debug, Deserialize, Clone)]
+pub struct SportsGame {
+    pub league: Option<String>,
+    pub stage: Option<String>,
+    pub stadium: Option<String>,
+    pub date: Option<String>,
+    pub time: Option<String>,
+    /// e.g. "Final" or "Live"
+    pub status: Option<String>,
+    pub teams: Option<Vec<SportsTeam>>,
+}
+
+/// Team taking part in a [`SportsGame`]
+#[derive(Debug, Deserialize, Clone)]
+pub struct SportsTeam {
+    pub name: Option<String>,
+    pub score: Option<String>,
+    pub thumbnail: Option<String>,
+}
+
 /// Search information
 #[derive(Debug, Deserialize, Clone)]
 pub struct SearchInformation {
@@ -380,5 +1055,93 @@ pub struct SerpapiPagination {
     pub current: Option<u32>,
     pub next: Option<String>,
     pub next_link: Option<String>,
+    pub next_page_token: Option<String>,
     pub other_pages: Option<HashMap<String, String>>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_box_variants() {
+        let weather: AnswerBox = serde_json::from_str(
+            r#"{"type": "weather_result", "temperature": "72", "unit": "Fahrenheit", "weather": "Sunny",
+                "forecast": [{"day": "Monday", "temperature": {"high": "75", "low": "60"}}]}"#,
+        )
+        .unwrap();
+        assert!(
+            matches!(&weather, AnswerBox::Weather(w) if w.temperature.as_deref() == Some("72"))
+        );
+        assert_eq!(weather.answer_type(), "weather_result");
+        assert_eq!(weather.text().as_deref(), Some("Sunny"));
+
+        let organic: AnswerBox = serde_json::from_str(
+            r#"{"type": "organic_result", "title": "Rust", "snippet": "A language empowering everyone.",
+                "link": "https://www.rust-lang.org/"}"#,
+        )
+        .unwrap();
+        assert_eq!(organic.link(), Some("https://www.rust-lang.org/"));
+        assert_eq!(organic.snippet(), Some("A language empowering everyone."));
+
+        let finance: AnswerBox = serde_json::from_str(
+            r#"{"type": "finance_results", "title": "Apple Inc", "price": 189.5, "currency": "USD",
+                "price_movement": {"price": 1.25, "percentage": 0.66, "movement": "Down", "date": "Today"}}"#,
+        )
+        .unwrap();
+        let AnswerBox::Finance(quote) = &finance else {
+            panic!("expected a finance answer");
+        };
+        let movement = quote.price_movement.as_ref().unwrap();
+        assert_eq!(movement.signed_value(), Some(-1.25));
+        assert_eq!(movement.date.as_deref(), Some("Today"));
+
+        // Unknown types keep their common fields
+        let other: AnswerBox = serde_json::from_str(
+            r#"{"type": "time_result", "title": "Local time", "answer": "3:15 PM"}"#,
+        )
+        .unwrap();
+        assert!(matches!(other, AnswerBox::Other(_)));
+        assert_eq!(other.answer_type(), "time_result");
+        assert_eq!(other.text().as_deref(), Some("3:15 PM"));
+    }
+
+    #[test]
+    fn test_serp_feature_blocks() {
+        let json = r#"{
+            "search_metadata": {"id": "abc"},
+            "search_parameters": {"engine": "google", "q": "rust"},
+            "ai_overview": {
+                "text_blocks": [
+                    {"type": "paragraph", "snippet": "Rust is a systems language.", "reference_indexes": [0]},
+                    {"type": "list", "list": [
+                        {"title": "Memory safe:", "snippet": "no garbage collector", "reference_indexes": [0]},
+                        {"snippet": "Fast"}
+                    ]},
+                    {"type": "expandable", "title": "History", "text_blocks": [
+                        {"type": "paragraph", "snippet": "Started at Mozilla."}
+                    ]}
+                ],
+                "references": [{"index": 0, "title": "Rust", "link": "https://www.rust-lang.org/"}]
+            },
+            "related_questions": [{"question": "Is Rust hard to learn?", "snippet": "It takes time."}],
+            "immersive_products": [{"title": "Rust Book", "source": "No Starch", "price": "$39.99",
+                "extracted_price": 39.99, "rating": 4.8, "reviews": 1200}],
+            "sports_results": {"title": "Rust FC", "game_spotlight": {"status": "Final",
+                "teams": [{"name": "Rust FC", "score": "2"}, {"name": "Go United", "score": "1"}]}}
+        }"#;
+        let results: SearchResults = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            results.ai_overview.unwrap().text(),
+            "Rust is a systems language.\nMemory safe: no garbage collector\nFast\nHistory\nStarted at Mozilla."
+        );
+        assert_eq!(
+            results.related_questions.unwrap()[0].question,
+            "Is Rust hard to learn?"
+        );
+        let summary = results.immersive_products.unwrap()[0].summary();
+        assert_eq!(summary.price.unwrap().currency.as_deref(), Some("USD"));
+        let game = results.sports_results.unwrap().game_spotlight.unwrap();
+        assert_eq!(game.teams.unwrap()[1].score.as_deref(), Some("1"));
+    }
+}
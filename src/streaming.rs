@@ -4,6 +4,7 @@ use tracing::{debug, error};
 
 use crate::{
     client::SerpClient,
+    engines::EngineQuery,
     error::{SerpError, SerpResult},
     query::SearchQueryBuilder,
     response::SearchResults,
@@ -192,6 +193,98 @@ impl SerpClient {
 
         Ok(all_results)
     }
+
+    /// Stream paginated results of a dedicated engine query
+    ///
+    /// Each engine decides how to reach its next page (offset, page number or
+    /// `next_page_token`) through [`EngineQuery::next_page`]. The stream ends when
+    /// there is no next page, after `config.max_pages` pages, or after the first
+    /// error. `config.page_size` is not applied; set the page size on the query.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::StreamExt;
+    /// use serp_sdk::{SerpClient, StreamConfig};
+    /// use serp_sdk::engines::google_maps::GoogleMapsQuery;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = SerpClient::builder().api_key("test").build()?;
+    /// let mut pages = client.engine_stream(
+    ///     GoogleMapsQuery::search("coffee"),
+    ///     StreamConfig::new().max_pages(3),
+    /// );
+    ///
+    /// while let Some(page) = pages.next().await {
+    ///     let page = page?;
+    ///     println!("Got {} places", page.local_results.map_or(0, |r| r.len()));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn engine_stream<Q>(
+        &self,
+        query: Q,
+        config: StreamConfig,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<Q::Response>> + Send + '_>>
+    where
+        Q: EngineQuery + 'static,
+        Q::Response: 'static,
+    {
+        let max_pages = config.max_pages;
+        let delay = config.delay_between_requests;
+
+        let pages = stream::unfold((Some(query), 0usize), move |(query, page)| async move {
+            let query = query?;
+            if page >= max_pages {
+                return None;
+            }
+            if page > 0 && !delay.is_zero() {
+                tokio::time::sleep(delay).await;
+            }
+
+            debug!("Fetching {} page {}", query.engine(), page + 1);
+            match self.search_engine(query.clone()).await {
+                Ok(response) => {
+                    let next = query.next_page(&response);
+                    Some((Ok(response), (next, page + 1)))
+                }
+                Err(e) => Some((Err(e), (None, page + 1))),
+            }
+        });
+
+        Box::pin(pages)
+    }
+
+    /// Stream individual items of a dedicated engine query across pages
+    ///
+    /// `items` extracts the items of interest (reviews, videos, jobs, ...) from
+    /// each page returned by [`engine_stream`](Self::engine_stream).
+    pub fn engine_items_stream<Q, T, F>(
+        &self,
+        query: Q,
+        config: StreamConfig,
+        items: F,
+    ) -> Pin<Box<dyn Stream<Item = SerpResult<T>> + Send + '_>>
+    where
+        Q: EngineQuery + 'static,
+        Q::Response: 'static,
+        T: Send + 'static,
+        F: Fn(Q::Response) -> Vec<T> + Send + 'static,
+    {
+        let items_stream = self
+            .engine_stream(query, config)
+            .flat_map(move |page_result| match page_result {
+                Ok(page) => stream::iter(items(page).into_iter().map(Ok)).left_stream(),
+                Err(e) => {
+                    error!("Failed to fetch page: {}", e);
+                    stream::once(async move { Err(e) }).right_stream()
+                }
+            });
+
+        Box::pin(items_stream)
+    }
 }
 
 #[cfg(test)]
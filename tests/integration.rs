@@ -91,5 +91,57 @@ async fn test_stream_config_validation() {
     assert!(result.is_err());
 }
 
-// Mock tests would go here if we were using wiremock
-// For now, we focus on testing the builder patterns and validation
+#[cfg(feature = "streaming")]
+#[tokio::test]
+async fn test_maps_reviews_stream_follows_page_tokens() {
+    use futures::StreamExt;
+    use serp_sdk::engines::google_maps::GoogleMapsReviewsQuery;
+    use serp_sdk::StreamConfig;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("engine", "google_maps_reviews"))
+        .and(query_param("next_page_token", "PAGE2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "search_metadata": {"id": "2"},
+            "reviews": [{"rating": 3.0, "snippet": "Okay"}]
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("engine", "google_maps_reviews"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "search_metadata": {"id": "1"},
+            "reviews": [{"rating": 5.0, "snippet": "Great"}],
+            "serpapi_pagination": {"next_page_token": "PAGE2"}
+        })))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+
+    let client = SerpClient::builder()
+        .api_key("test-key")
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    let reviews: Vec<_> = client
+        .maps_reviews_stream(
+            GoogleMapsReviewsQuery::new("0x1:0x2"),
+            StreamConfig::new().delay(Duration::ZERO),
+        )
+        .collect()
+        .await;
+
+    let snippets: Vec<_> = reviews
+        .into_iter()
+        .map(|r| r.unwrap().snippet.unwrap())
+        .collect();
+    assert_eq!(snippets, vec!["Great", "Okay"]);
+}